@@ -25,18 +25,65 @@
 //! - Refactor the Sork code to use the Saltshore implementation.
 
 use saltshore::prelude::*;
+use std::sync::Arc;
+
+/// The world file, which contains all of the rooms, objects, and messages.
+const WORLD_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/sork/world.toml");
+
+/// The magic word, which doesn't do what it did in Colossal Cave.
+#[derive(Clone, Copy, Debug)]
+struct XyzzyCommand;
+
+impl CustomCommand for XyzzyCommand {
+  fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    game_state.enqueue_output("A hollow voice says \"Fool.\"".to_string());
+    Ok(())
+  }
+}
+
+/// The definition of the magic word.
+#[derive(Clone, Copy, Debug)]
+struct Xyzzy;
+
+impl CommandDefinition for Xyzzy {
+  fn verbs(&self) -> Vec<String> {
+    vec!["xyzzy".to_string(), "plugh".to_string()]
+  }
+
+  fn parse(&self, action: &Action, _game_state: &GameState) -> Result<Command, ParserError> {
+    if !action.is_bare() {
+      return Err(ParserError::InvalidInput(action.verb.clone()));
+    }
+    Ok(Command::Custom(Arc::new(XyzzyCommand)))
+  }
+}
+
+/// The sailor's greeting, which only does anything at the Flathead Ocean.
+#[derive(Clone, Copy, Debug)]
+struct HelloSailorCommand;
+
+impl CustomCommand for HelloSailorCommand {
+  fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    game_state.enqueue_output("Nothing happens here.".to_string());
+    Ok(())
+  }
+}
 
 fn main() -> Result<(), GameError> {
-  println!(
-    r#"
-  SORK I: The Great Underground Empire
-  Apologies and thanks to Tim Anderson, Marc Blank, Bruce Daniels, Dave Lebling,
-  and to everyone else who created the original ZORK and other Infocom games.
-  ZORK is a registered trademark of Infocom, Inc.
-  Revision 1 / Serial number 12345
-    "#
-  );
-  let mut game_loop = GameLoop::default();
+  let mut game_loop = GameLoop::default()
+    .with_world_file(WORLD_FILE)
+    .with_command(Xyzzy)
+    .with_phrase("hello sailor", Command::Custom(Arc::new(HelloSailorCommand)))
+    .with_npc(
+      "thief",
+      Npc::new(
+        "thief",
+        "There is a suspicious-looking individual, holding a large bag, leaning against one wall.",
+      )
+      .at("cellar")
+      .wander(3, &["kitchen", "living_room", "cellar"])
+      .with_combatant(Combatant::new(8, 3, 1)),
+    );
   game_loop.run()?;
   Ok(())
   // As a player, I need to be able to see the game state so that I can
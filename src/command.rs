@@ -5,6 +5,8 @@ pub mod command;
 pub mod commands;
 /// An error that can occur when executing a command.
 pub mod error;
+/// A registry of commands defined outside saltshore.
+pub mod registry;
 
 /// The prelude for the command module.
 pub mod prelude {
@@ -12,4 +14,5 @@ pub mod prelude {
   pub use crate::command::commands;
   pub use crate::command::commands::*;
   pub use crate::command::error::CommandError;
+  pub use crate::command::registry::{CommandDefinition, CommandRegistry, CustomCommand};
 }
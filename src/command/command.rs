@@ -1,6 +1,9 @@
 use super::commands::*;
 use crate::command::prelude::CommandError;
+use crate::command::registry::CustomCommand;
 use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+use std::sync::Arc;
 
 /// Actual commands that can be executed.
 #[derive(Clone, Debug, PartialEq)]
@@ -11,6 +14,60 @@ pub enum Command {
   ThrowError(ThrowErrorCommand),
   /// Quit command.
   Quit(QuitCommand),
+  /// Go command.
+  Go(GoCommand),
+  /// Look command.
+  Look(LookCommand),
+  /// Look in command.
+  LookIn(LookInCommand),
+  /// Examine command.
+  Examine(ExamineCommand),
+  /// Take command.
+  Take(TakeCommand),
+  /// Drop command.
+  Drop(DropCommand),
+  /// Put command.
+  Put(PutCommand),
+  /// Inventory command.
+  Inventory(InventoryCommand),
+  /// Save command.
+  Save(SaveCommand),
+  /// Restore command.
+  Restore(RestoreCommand),
+  /// Undo command.
+  Undo(UndoCommand),
+  /// Again command.
+  Again(AgainCommand),
+  /// Wait command.
+  Wait(WaitCommand),
+  /// Say command, which carries free text.
+  Say(SayCommand),
+  /// Write command, which carries free text.
+  Write(WriteCommand),
+  /// Alias command.
+  Alias(AliasCommand),
+  /// Aliases command.
+  Aliases(AliasesCommand),
+  /// Time command.
+  Time(TimeCommand),
+  /// Switch command.
+  Switch(SwitchCommand),
+  /// Open command.
+  Open(OpenCommand),
+  /// Close command.
+  Close(CloseCommand),
+  /// Lock command.
+  Lock(LockCommand),
+  /// Unlock command.
+  Unlock(UnlockCommand),
+  /// Attack command.
+  Attack(AttackCommand),
+  /// Diagnose command.
+  Diagnose(DiagnoseCommand),
+  /// A command whose output is labelled, e.g. with the object it acts upon.
+  Labelled(LabelledCommand),
+  /// A command defined outside saltshore.
+  Custom(Arc<dyn CustomCommand>),
 }
 
 impl Command {
@@ -20,9 +77,111 @@ impl Command {
       Command::Succeed(command) => command.execute(game_state)?,
       Command::ThrowError(command) => command.execute(game_state)?,
       Command::Quit(command) => command.execute(game_state)?,
+      Command::Go(command) => command.execute(game_state)?,
+      Command::Look(command) => command.execute(game_state)?,
+      Command::LookIn(command) => command.execute(game_state)?,
+      Command::Examine(command) => command.execute(game_state)?,
+      Command::Take(command) => command.execute(game_state)?,
+      Command::Drop(command) => command.execute(game_state)?,
+      Command::Put(command) => command.execute(game_state)?,
+      Command::Inventory(command) => command.execute(game_state)?,
+      Command::Save(command) => command.execute(game_state)?,
+      Command::Restore(command) => command.execute(game_state)?,
+      Command::Undo(command) => command.execute(game_state)?,
+      Command::Again(command) => command.execute(game_state)?,
+      Command::Wait(command) => command.execute(game_state)?,
+      Command::Say(command) => command.execute(game_state)?,
+      Command::Write(command) => command.execute(game_state)?,
+      Command::Alias(command) => command.execute(game_state)?,
+      Command::Aliases(command) => command.execute(game_state)?,
+      Command::Time(command) => command.execute(game_state)?,
+      Command::Switch(command) => command.execute(game_state)?,
+      Command::Open(command) => command.execute(game_state)?,
+      Command::Close(command) => command.execute(game_state)?,
+      Command::Lock(command) => command.execute(game_state)?,
+      Command::Unlock(command) => command.execute(game_state)?,
+      Command::Attack(command) => command.execute(game_state)?,
+      Command::Diagnose(command) => command.execute(game_state)?,
+      Command::Labelled(command) => command.execute(game_state)?,
+      Command::Custom(command) => command.execute(game_state)?,
     }
     Ok(())
   }
+
+  /// Whether the command can be undone.
+  ///
+  /// Commands that manage the game's history (e.g. saving, restoring, and
+  /// undoing itself) cannot be undone, and nor can those that manage the
+  /// player's aliases. Nor can "again", since the command it repeats is
+  /// undoable in its own right.
+  pub fn is_undoable(&self) -> bool {
+    if let Command::Labelled(command) = self {
+      return command.command.is_undoable();
+    }
+    !matches!(
+      self,
+      Command::Save(_)
+        | Command::Restore(_)
+        | Command::Undo(_)
+        | Command::Again(_)
+        | Command::Alias(_)
+        | Command::Aliases(_)
+    )
+  }
+
+  /// Whether the command takes game time, letting the world move on.
+  ///
+  /// Movement and actions take time. Meta commands, which look at or manage
+  /// the game rather than act within it (e.g. "look", "inventory", "save",
+  /// and debugging commands), do not. Nor does "again", since the command it
+  /// repeats takes time in its own right.
+  pub fn takes_time(&self) -> bool {
+    match self {
+      Command::Labelled(command) => command.command.takes_time(),
+      Command::Custom(command) => command.takes_time(),
+      Command::Succeed(_)
+      | Command::ThrowError(_)
+      | Command::Quit(_)
+      | Command::Look(_)
+      | Command::Inventory(_)
+      | Command::Save(_)
+      | Command::Restore(_)
+      | Command::Undo(_)
+      | Command::Again(_)
+      | Command::Alias(_)
+      | Command::Aliases(_)
+      | Command::Time(_)
+      | Command::Diagnose(_) => false,
+      _ => true,
+    }
+  }
+
+  /// Whether the command can be repeated by "again".
+  pub fn is_repeatable(&self) -> bool {
+    !matches!(self, Command::Again(_))
+  }
+
+  /// The objects the command acts upon, which later pronouns (e.g. "it")
+  /// refer to.
+  pub fn objects(&self) -> Vec<ObjectId> {
+    match self {
+      Command::LookIn(command) => vec![command.container.clone()],
+      Command::Examine(command) => vec![command.object.clone()],
+      Command::Take(command) => vec![command.object.clone()],
+      Command::Drop(command) => vec![command.object.clone()],
+      Command::Put(command) => vec![command.object.clone()],
+      Command::Write(command) => vec![command.object.clone()],
+      Command::Switch(command) => vec![command.object.clone()],
+      Command::Open(command) => vec![command.object.clone()],
+      Command::Close(command) => vec![command.object.clone()],
+      Command::Lock(command) => vec![command.object.clone()],
+      Command::Unlock(command) => vec![command.object.clone()],
+      Command::Attack(command) => command.weapon.iter().cloned().collect(),
+      Command::Labelled(command) => command.command.objects(),
+      Command::Custom(command) => command.objects(),
+      _ => Vec::new(),
+    }
+  }
 }
 
 #[cfg(test)]
@@ -36,12 +195,52 @@ mod tests {
     test_utils::init();
     let mut game_state = GameState::default();
     let command = Command::Quit(QuitCommand);
-    assert_eq!(game_state.quit_flag(), false);
+    assert!(!game_state.quit_flag());
     command.execute(&mut game_state)?;
-    assert_eq!(game_state.quit_flag(), true);
+    assert!(game_state.quit_flag());
     Ok(())
   }
 
+  #[test]
+  fn test_execute_go() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    let command = Command::Go(GoCommand::new("north"));
+    let result = command.execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter("You can't go that way.".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_objects() {
+    test_utils::init();
+    assert_eq!(
+      Command::Put(PutCommand::new_in("lantern", "mailbox")).objects(),
+      vec!["lantern"]
+    );
+    assert_eq!(Command::Look(LookCommand).objects(), Vec::<ObjectId>::new());
+  }
+
+  #[test]
+  fn test_is_undoable() {
+    test_utils::init();
+    assert!(Command::Go(GoCommand::new("north")).is_undoable());
+    assert!(!Command::Save(SaveCommand::new("game")).is_undoable());
+    assert!(!Command::Undo(UndoCommand::new(1)).is_undoable());
+  }
+
+  #[test]
+  fn test_takes_time() {
+    test_utils::init();
+    assert!(Command::Go(GoCommand::new("north")).takes_time());
+    assert!(Command::Labelled(LabelledCommand::new("lamp", Command::Take(TakeCommand::new("lamp")))).takes_time());
+    assert!(!Command::Look(LookCommand).takes_time());
+    assert!(!Command::Inventory(InventoryCommand).takes_time());
+    assert!(!Command::Save(SaveCommand::new("game")).takes_time());
+  }
+
   #[test]
   fn test_execute_succeed() -> Result<(), CommandError> {
     test_utils::init();
@@ -59,7 +258,7 @@ mod tests {
       error: CommandError::InCharacter("Test error.".to_string()),
     });
     let result = command.execute(&mut game_state);
-    assert_eq!(result.is_err(), true);
+    assert!(result.is_err());
     assert_eq!(result.unwrap_err().to_string(), "Test error.");
     Ok(())
   }
@@ -1,12 +1,116 @@
+/// Again command module
+pub mod again;
+/// Again command module
+pub use again::AgainCommand;
+/// Alias command module
+pub mod alias;
+/// Alias command module
+pub use alias::AliasCommand;
+/// Aliases command module
+pub mod aliases;
+/// Aliases command module
+pub use aliases::AliasesCommand;
+/// Attack command module
+pub mod attack;
+/// Attack command module
+pub use attack::{AttackCommand, AttackTarget};
+/// Close command module
+pub mod close;
+/// Close command module
+pub use close::CloseCommand;
+/// Diagnose command module
+pub mod diagnose;
+/// Diagnose command module
+pub use diagnose::DiagnoseCommand;
+/// Drop command module
+pub mod drop;
+/// Drop command module
+pub use drop::DropCommand;
+/// Examine command module
+pub mod examine;
+/// Examine command module
+pub use examine::ExamineCommand;
+/// Go command module
+pub mod go;
+/// Go command module
+pub use go::GoCommand;
+/// Inventory command module
+pub mod inventory;
+/// Inventory command module
+pub use inventory::InventoryCommand;
+/// Labelled command module
+pub mod labelled;
+/// Labelled command module
+pub use labelled::LabelledCommand;
+/// Lock command module
+pub mod lock;
+/// Lock command module
+pub use lock::LockCommand;
+/// Look command module
+pub mod look;
+/// Look command module
+pub use look::LookCommand;
+/// Look in command module
+pub mod look_in;
+/// Look in command module
+pub use look_in::LookInCommand;
+/// Open command module
+pub mod open;
+/// Open command module
+pub use open::OpenCommand;
+/// Put command module
+pub mod put;
+/// Put command module
+pub use put::PutCommand;
 /// Quit command module
 pub mod quit;
 /// Quit command module
 pub use quit::QuitCommand;
+/// Restore command module
+pub mod restore;
+/// Restore command module
+pub use restore::RestoreCommand;
+/// Say command module
+pub mod say;
+/// Say command module
+pub use say::SayCommand;
+/// Save command module
+pub mod save;
+/// Save command module
+pub use save::SaveCommand;
 /// Succeed command module
 pub mod succeed;
 /// Succeed command module
 pub use succeed::SucceedCommand;
+/// Switch command module
+pub mod switch;
+/// Switch command module
+pub use switch::SwitchCommand;
+/// Take command module
+pub mod take;
+/// Take command module
+pub use take::TakeCommand;
 /// Throw error command module
 pub mod throw_error;
 /// Throw error command module
 pub use throw_error::ThrowErrorCommand;
+/// Time command module
+pub mod time;
+/// Time command module
+pub use time::TimeCommand;
+/// Undo command module
+pub mod undo;
+/// Undo command module
+pub use undo::UndoCommand;
+/// Unlock command module
+pub mod unlock;
+/// Unlock command module
+pub use unlock::UnlockCommand;
+/// Wait command module
+pub mod wait;
+/// Wait command module
+pub use wait::WaitCommand;
+/// Write command module
+pub mod write;
+/// Write command module
+pub use write::WriteCommand;
@@ -0,0 +1,51 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Again command, which repeats the previous command.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AgainCommand;
+
+impl AgainCommand {
+  /// Execute the command.
+  ///
+  /// The previous command is put back on the command queue rather than
+  /// executed directly, so it is handled exactly as it was the first time.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let command = game_state
+      .last_command
+      .clone()
+      .ok_or_else(|| CommandError::OutOfCharacter("There is nothing to repeat.".to_string()))?;
+    game_state.enqueue_command(command);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::command::prelude::{Command, LookCommand};
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState {
+      last_command: Some(Command::Look(LookCommand)),
+      ..GameState::default()
+    };
+    AgainCommand.execute(&mut game_state)?;
+    assert_eq!(game_state.dequeue_command(), Some(Command::Look(LookCommand)));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_nothing_to_repeat() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    assert_eq!(
+      AgainCommand.execute(&mut game_state),
+      Err(CommandError::OutOfCharacter("There is nothing to repeat.".to_string()))
+    );
+  }
+}
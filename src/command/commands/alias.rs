@@ -0,0 +1,79 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Alias command, which defines (or removes) an alias or macro: a name that
+/// stands for one or more inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasCommand {
+  /// The name of the alias, in lowercase (e.g. "kk").
+  pub name: String,
+  /// The inputs the alias stands for (e.g. "kill kobold with sword"), or none
+  /// to remove the alias.
+  pub inputs: Vec<String>,
+}
+
+impl AliasCommand {
+  /// Create a new command to define an alias or macro.
+  pub fn new(name: &str, inputs: &[&str]) -> Self {
+    Self {
+      name: name.to_lowercase(),
+      inputs: inputs.iter().map(|input| input.to_string()).collect(),
+    }
+  }
+
+  /// Create a new command to remove an alias or macro.
+  pub fn remove(name: &str) -> Self {
+    Self::new(name, &[])
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    if self.inputs.is_empty() {
+      if game_state.aliases.remove(&self.name).is_none() {
+        return Err(CommandError::OutOfCharacter(format!("'{}' isn't an alias.", self.name)));
+      }
+      game_state.enqueue_output(format!("'{}' no longer means anything.", self.name));
+      return Ok(());
+    }
+    game_state.aliases.insert(self.name.clone(), self.inputs.clone());
+    game_state.enqueue_output(format!("'{}' now means '{}'.", self.name, self.inputs.join("; ")));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    AliasCommand::new("DIG", &["take shovel", "dig", "drop shovel"]).execute(&mut game_state)?;
+    assert_eq!(
+      game_state.aliases.get("dig"),
+      Some(&vec![
+        "take shovel".to_string(),
+        "dig".to_string(),
+        "drop shovel".to_string()
+      ])
+    );
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("'dig' now means 'take shovel; dig; drop shovel'.".to_string())
+    );
+    AliasCommand::remove("dig").execute(&mut game_state)?;
+    assert!(game_state.aliases.is_empty());
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("'dig' no longer means anything.".to_string())
+    );
+    assert_eq!(
+      AliasCommand::remove("dig").execute(&mut game_state),
+      Err(CommandError::OutOfCharacter("'dig' isn't an alias.".to_string()))
+    );
+    Ok(())
+  }
+}
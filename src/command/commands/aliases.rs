@@ -0,0 +1,50 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Aliases command, which lists the player's aliases and macros.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AliasesCommand;
+
+impl AliasesCommand {
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    if game_state.aliases.is_empty() {
+      return Err(CommandError::OutOfCharacter(
+        "You haven't defined any aliases.".to_string(),
+      ));
+    }
+    let lines: Vec<String> = game_state
+      .aliases
+      .iter()
+      .map(|(name, inputs)| format!("{} = {}", name, inputs.join("; ")))
+      .collect();
+    game_state.enqueue_output(lines.join("\n"));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    assert!(AliasesCommand.execute(&mut game_state).is_err());
+    game_state
+      .aliases
+      .insert("kk".to_string(), vec!["kill kobold".to_string()]);
+    game_state
+      .aliases
+      .insert("dig".to_string(), vec!["take shovel".to_string(), "dig".to_string()]);
+    AliasesCommand.execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("dig = take shovel; dig\nkk = kill kobold".to_string())
+    );
+    Ok(())
+  }
+}
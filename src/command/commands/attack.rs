@@ -0,0 +1,194 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::{GameState, NpcId};
+use crate::world::prelude::{Location, ObjectId};
+
+/// What the player attacks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttackTarget {
+  /// A non-player character.
+  Npc(NpcId),
+  /// An object, which can't be fought.
+  Object(ObjectId),
+}
+
+/// Attack command, which strikes a non-player character, bare-handed or
+/// with a weapon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttackCommand {
+  /// The non-player character to attack, or an object that the player tried
+  /// to attack instead.
+  pub target: AttackTarget,
+  /// The weapon to attack with, if any.
+  pub weapon: Option<ObjectId>,
+}
+
+impl AttackCommand {
+  /// Create a new attack command.
+  pub fn new(target: AttackTarget, weapon: Option<&str>) -> Self {
+    Self {
+      target,
+      weapon: weapon.map(str::to_string),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let (damage, with) = match &self.weapon {
+      Some(id) => {
+        let weapon = game_state
+          .world
+          .object(id)
+          .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", id)))?;
+        if weapon.location != Location::Player {
+          return Err(CommandError::InCharacter(format!(
+            "You aren't holding {}.",
+            weapon.definite_name()
+          )));
+        }
+        if weapon.damage == 0 {
+          return Err(CommandError::InCharacter(format!(
+            "You can't attack anything with {}.",
+            weapon.definite_name()
+          )));
+        }
+        (weapon.damage, format!(" with {}", weapon.definite_name()))
+      },
+      None => (game_state.player.damage, String::new()),
+    };
+    let id = match &self.target {
+      AttackTarget::Npc(id) => id,
+      AttackTarget::Object(_) => {
+        return Err(CommandError::InCharacter(
+          "Violence isn't the answer to this one.".to_string(),
+        ))
+      },
+    };
+    let npc = game_state
+      .npcs
+      .get_mut(id)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such character '{}'.", id)))?;
+    if npc.location != game_state.world.player_location {
+      return Err(CommandError::InCharacter(format!(
+        "You can't see any {} here.",
+        npc.name
+      )));
+    }
+    let Some(combatant) = npc.combatant.as_mut() else {
+      return Err(CommandError::InCharacter(format!(
+        "The {} has no quarrel with you.",
+        npc.name
+      )));
+    };
+    npc.hostile = true;
+    combatant.take_hit(damage, 0);
+    let mut output = format!("You hit the {}{}.", npc.name, with);
+    if combatant.is_dead() {
+      output.push_str(&format!("\nThe {} dies.", npc.name));
+      game_state.npcs.remove(id);
+    }
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::{Combatant, Npc};
+  use crate::test_utils;
+  use crate::world::prelude::Object;
+
+  /// The test state, with a goblin in the room and a sword in the player's
+  /// hand.
+  fn test_state() -> GameState {
+    let mut game_state = test_utils::test_state();
+    game_state.world = game_state.world.with_object(
+      "sword",
+      Object::new("elvish sword", "").at(Location::Player).with_damage(3),
+    );
+    game_state.npcs.insert(
+      "goblin".to_string(),
+      Npc::new("goblin", "")
+        .at("west_of_house")
+        .with_combatant(Combatant::new(5, 2, 1)),
+    );
+    game_state
+  }
+
+  /// The goblin, as a target.
+  fn goblin_target() -> AttackTarget {
+    AttackTarget::Npc("goblin".to_string())
+  }
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_state();
+    AttackCommand::new(goblin_target(), Some("sword")).execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You hit the goblin with the elvish sword.".to_string())
+    );
+    let goblin = &game_state.npcs["goblin"];
+    assert!(goblin.hostile);
+    assert_eq!(goblin.combatant.unwrap().hit_points, 3);
+    AttackCommand::new(goblin_target(), None).execute(&mut game_state)?;
+    AttackCommand::new(goblin_target(), Some("sword")).execute(&mut game_state)?;
+    game_state.dequeue_output();
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You hit the goblin with the elvish sword.\nThe goblin dies.".to_string())
+    );
+    assert!(!game_state.npcs.contains_key("goblin"));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_errors() {
+    test_utils::init();
+    let mut game_state = test_state();
+    for (command, error) in [
+      (
+        AttackCommand::new(goblin_target(), Some("leaflet")),
+        "You aren't holding the leaflet.",
+      ),
+      (
+        AttackCommand::new(goblin_target(), Some("lantern")),
+        "You can't attack anything with the brass lantern.",
+      ),
+      (
+        AttackCommand::new(AttackTarget::Object("mailbox".to_string()), None),
+        "Violence isn't the answer to this one.",
+      ),
+    ] {
+      assert_eq!(
+        command.execute(&mut game_state),
+        Err(CommandError::InCharacter(error.to_string()))
+      );
+    }
+    // An object is never taken for a character of the same name.
+    game_state.world = game_state.world.with_object(
+      "goblin",
+      Object::new("goblin statue", "").at(Location::Room("west_of_house".to_string())),
+    );
+    assert_eq!(
+      AttackCommand::new(AttackTarget::Object("goblin".to_string()), None).execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "Violence isn't the answer to this one.".to_string()
+      ))
+    );
+    assert_eq!(game_state.npcs["goblin"].combatant.unwrap().hit_points, 5);
+    game_state.npcs.get_mut("goblin").unwrap().combatant = None;
+    assert_eq!(
+      AttackCommand::new(goblin_target(), None).execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "The goblin has no quarrel with you.".to_string()
+      ))
+    );
+    game_state.world.player_location = "north_of_house".to_string();
+    assert_eq!(
+      AttackCommand::new(goblin_target(), None).execute(&mut game_state),
+      Err(CommandError::InCharacter("You can't see any goblin here.".to_string()))
+    );
+  }
+}
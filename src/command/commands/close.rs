@@ -0,0 +1,71 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+
+/// Close command, which closes a door or container.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CloseCommand {
+  /// The object to close.
+  pub object: ObjectId,
+}
+
+impl CloseCommand {
+  /// Create a new close command.
+  pub fn new(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if !object.openable {
+      return Err(CommandError::InCharacter(format!(
+        "You can't close {}.",
+        object.definite_name()
+      )));
+    }
+    if object.closed {
+      return Err(CommandError::InCharacter(format!(
+        "The {} is already closed.",
+        object.name
+      )));
+    }
+    object.closed = true;
+    game_state.enqueue_output("Closed.".to_string());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world.object_mut("mailbox").unwrap().openable = true;
+    CloseCommand::new("mailbox").execute(&mut game_state)?;
+    assert!(game_state.world.object("mailbox").unwrap().closed);
+    assert_eq!(game_state.dequeue_output(), Some("Closed.".to_string()));
+    assert_eq!(
+      CloseCommand::new("mailbox").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "The small mailbox is already closed.".to_string()
+      ))
+    );
+    assert_eq!(
+      CloseCommand::new("lantern").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "You can't close the brass lantern.".to_string()
+      ))
+    );
+    Ok(())
+  }
+}
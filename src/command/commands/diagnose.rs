@@ -0,0 +1,38 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Diagnose command, which tells the player how healthy they are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiagnoseCommand;
+
+impl DiagnoseCommand {
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let player = &game_state.player;
+    let output = format!(
+      "You have {} of {} hit points.",
+      player.hit_points, player.max_hit_points
+    );
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.player.take_hit(3, 0);
+    DiagnoseCommand.execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You have 7 of 10 hit points.".to_string())
+    );
+    Ok(())
+  }
+}
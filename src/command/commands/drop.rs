@@ -0,0 +1,64 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::{Location, ObjectId};
+
+/// Drop command, which puts down an object the player is carrying.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropCommand {
+  /// The object to drop.
+  pub object: ObjectId,
+}
+
+impl DropCommand {
+  /// Create a new drop command.
+  pub fn new(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let room = Location::Room(game_state.world.player_location.clone());
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if object.location != Location::Player {
+      return Err(CommandError::InCharacter("You're not carrying that.".to_string()));
+    }
+    object.location = room;
+    game_state.enqueue_output("Dropped.".to_string());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    DropCommand::new("lantern").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.world.object("lantern").unwrap().location,
+      Location::Room("west_of_house".to_string())
+    );
+    assert_eq!(game_state.dequeue_output(), Some("Dropped.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_not_carried() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = DropCommand::new("leaflet").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter("You're not carrying that.".to_string()))
+    );
+  }
+}
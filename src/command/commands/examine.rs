@@ -0,0 +1,95 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+
+/// Examine command, which describes an object in detail.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExamineCommand {
+  /// The object to examine.
+  pub object: ObjectId,
+}
+
+impl ExamineCommand {
+  /// Create a new examine command.
+  pub fn new(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let world = &game_state.world;
+    let object = world
+      .object(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    let mut lines = vec![if object.description.is_empty() {
+      format!("You see nothing special about {}.", object.definite_name())
+    } else {
+      world.clock.fill_in(&object.description)
+    }];
+    lines.extend(object.describe_openness());
+    lines.extend(object.writing.iter().map(|text| format!("Written on it: \"{}\"", text)));
+    if object.reveals_contents() {
+      lines.extend(world.describe_contents(&self.object));
+    }
+    game_state.enqueue_output(lines.join("\n"));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    ExamineCommand::new("lantern").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("A battery-powered brass lantern.".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_contents() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world.object_mut("mailbox").unwrap().description = String::new();
+    ExamineCommand::new("mailbox").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You see nothing special about the small mailbox.\nInside the small mailbox is: a leaflet.".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_writing() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let leaflet = game_state.world.object_mut("leaflet").unwrap();
+    leaflet.writing = vec!["XYZZY".to_string(), "plugh".to_string()];
+    let description = leaflet.description.clone();
+    ExamineCommand::new("leaflet").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some(format!(
+        "{}\nWritten on it: \"XYZZY\"\nWritten on it: \"plugh\"",
+        description
+      ))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_missing() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    assert!(ExamineCommand::new("unicorn").execute(&mut game_state).is_err());
+  }
+}
@@ -0,0 +1,146 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::Direction;
+
+/// Go command, which moves the player through an exit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GoCommand {
+  /// The name of the exit (e.g. "north" or "house").
+  pub exit: String,
+}
+
+impl GoCommand {
+  /// Create a new go command.
+  ///
+  /// Abbreviated directions (e.g. "n") are expanded to the canonical name of
+  /// the exit (e.g. "north").
+  pub fn new(exit: &str) -> Self {
+    let exit = match Direction::from_word(exit) {
+      Some(direction) => direction.name().to_string(),
+      None => exit.to_string(),
+    };
+    Self { exit }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let world = &mut game_state.world;
+    let exit = world
+      .current_room()
+      .and_then(|room| room.exit(&self.exit))
+      .ok_or_else(|| CommandError::InCharacter("You can't go that way.".to_string()))?;
+    if let Some(door) = exit.door.as_ref().and_then(|door| world.object(door)) {
+      if door.closed {
+        return Err(CommandError::InCharacter(format!("The {} is closed.", door.name)));
+      }
+    }
+    let destination = exit.destination.clone();
+    if world.room(&destination).is_none() {
+      return Err(CommandError::Unexpected(format!(
+        "The exit '{}' leads to a nonexistent room '{}'.",
+        self.exit, destination
+      )));
+    }
+    world.player_location = destination;
+    if let Some(description) = game_state.describe_current_room() {
+      game_state.enqueue_output(description);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+  use crate::world::prelude::{Object, Room, World};
+
+  fn test_state() -> GameState {
+    GameState {
+      world: World::new()
+        .with_room(
+          "west_of_house",
+          Room::new("West of House", "An open field.")
+            .with_exit("north", "north_of_house")
+            .with_exit("house", "living_room")
+            .with_exit("south", "nowhere"),
+        )
+        .with_room("north_of_house", Room::new("North of House", "The north side."))
+        .with_room("living_room", Room::new("Living Room", "A living room."))
+        .with_player_location("west_of_house"),
+      ..GameState::default()
+    }
+  }
+
+  #[test]
+  fn test_new() {
+    test_utils::init();
+    assert_eq!(GoCommand::new("n").exit, "north");
+    assert_eq!(GoCommand::new("north").exit, "north");
+    assert_eq!(GoCommand::new("house").exit, "house");
+  }
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_state();
+    GoCommand::new("n").execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "north_of_house");
+    assert!(game_state.dequeue_output().unwrap().starts_with("North of House\n"));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_named_exit() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_state();
+    GoCommand::new("house").execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "living_room");
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_no_exit() {
+    test_utils::init();
+    let mut game_state = test_state();
+    let result = GoCommand::new("west").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter("You can't go that way.".to_string()))
+    );
+    assert_eq!(game_state.world.player_location, "west_of_house");
+  }
+
+  #[test]
+  fn test_execute_through_door() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_state();
+    game_state.world = game_state
+      .world
+      .with_room(
+        "living_room",
+        Room::new("Living Room", "A living room.").with_exit_through("down", "cellar", "trap_door"),
+      )
+      .with_room("cellar", Room::new("Cellar", "A cellar."))
+      .with_object("trap_door", Object::new("trap door", "").fixed().openable().closed())
+      .with_player_location("living_room");
+    assert_eq!(
+      GoCommand::new("down").execute(&mut game_state),
+      Err(CommandError::InCharacter("The trap door is closed.".to_string()))
+    );
+    game_state.world.object_mut("trap_door").unwrap().closed = false;
+    GoCommand::new("down").execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "cellar");
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_missing_room() {
+    test_utils::init();
+    let mut game_state = test_state();
+    let result = GoCommand::new("south").execute(&mut game_state);
+    assert!(matches!(result, Err(CommandError::Unexpected(_))));
+    assert_eq!(game_state.world.player_location, "west_of_house");
+  }
+}
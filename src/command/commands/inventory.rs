@@ -0,0 +1,56 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Inventory command, which lists what the player is carrying.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InventoryCommand;
+
+impl InventoryCommand {
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let world = &game_state.world;
+    let inventory = world.inventory();
+    let output = if inventory.is_empty() {
+      "You are empty-handed.".to_string()
+    } else {
+      let mut lines = vec!["You are carrying:".to_string()];
+      for id in inventory {
+        lines.push(format!("  {}", world.objects[&id].indefinite_name()));
+        if world.objects[&id].reveals_contents() {
+          lines.extend(world.describe_contents(&id).map(|contents| format!("    {}", contents)));
+        }
+      }
+      lines.join("\n")
+    };
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    InventoryCommand.execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You are carrying:\n  a brass lantern".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_empty_handed() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    InventoryCommand.execute(&mut game_state)?;
+    assert_eq!(game_state.dequeue_output(), Some("You are empty-handed.".to_string()));
+    Ok(())
+  }
+}
@@ -0,0 +1,97 @@
+use crate::command::prelude::{Command, CommandError};
+use crate::game::prelude::GameState;
+
+/// Labelled command, which executes another command and prefixes its output
+/// with a label.
+///
+/// This is used when one input acts upon several objects (e.g. "take all"),
+/// so that the player can tell which result belongs to which object (e.g.
+/// "brass lantern: Taken."). A failure is reported in the same way rather than
+/// returned as an error, so that the remaining objects are still handled;
+/// only unexpected errors and the player's death are returned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelledCommand {
+  /// The label (e.g. "brass lantern").
+  pub label: String,
+  /// The command to execute.
+  pub command: Box<Command>,
+}
+
+impl LabelledCommand {
+  /// Create a new labelled command.
+  pub fn new(label: &str, command: Command) -> Self {
+    Self {
+      label: label.to_string(),
+      command: Box::new(command),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let start = game_state.output_queue.len();
+    match self.command.execute(game_state) {
+      Ok(()) => {},
+      Err(error @ CommandError::Unexpected(_)) => return Err(error),
+      Err(error @ CommandError::Death(_)) => return Err(error),
+      Err(error) => game_state.enqueue_output(error.to_string()),
+    }
+    for output in game_state.output_queue.iter_mut().skip(start) {
+      *output = format!("{}: {}", self.label, output);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::command::prelude::{GoCommand, TakeCommand, ThrowErrorCommand};
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.enqueue_output("Earlier output.".to_string());
+    LabelledCommand::new("leaflet", Command::Take(TakeCommand::new("leaflet"))).execute(&mut game_state)?;
+    assert_eq!(game_state.dequeue_output(), Some("Earlier output.".to_string()));
+    assert_eq!(game_state.dequeue_output(), Some("leaflet: Taken.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_failure() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    LabelledCommand::new("small mailbox", Command::Take(TakeCommand::new("mailbox"))).execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("small mailbox: You can't take the small mailbox.".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_unexpected_failure() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let command = LabelledCommand::new("south", Command::Go(GoCommand::new("south")));
+    assert!(matches!(
+      command.execute(&mut game_state),
+      Err(CommandError::Unexpected(_))
+    ));
+  }
+
+  #[test]
+  fn test_execute_death() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let error = CommandError::Death("The leaflet bites you.".to_string());
+    let command = LabelledCommand::new(
+      "leaflet",
+      Command::ThrowError(ThrowErrorCommand { error: error.clone() }),
+    );
+    assert_eq!(command.execute(&mut game_state), Err(error));
+    assert!(game_state.output_queue.is_empty());
+  }
+}
@@ -0,0 +1,146 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::{Location, ObjectId, World};
+
+/// Lock command, which locks a door or container with a key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockCommand {
+  /// The object to lock.
+  pub object: ObjectId,
+  /// The key to lock it with.
+  pub key: ObjectId,
+}
+
+impl LockCommand {
+  /// Create a new lock command.
+  pub fn new(object: &str, key: &str) -> Self {
+    Self {
+      object: object.to_string(),
+      key: key.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    check_key(&game_state.world, &self.object, &self.key, "lock")?;
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if object.locked {
+      return Err(CommandError::InCharacter(format!(
+        "The {} is already locked.",
+        object.name
+      )));
+    }
+    if !object.closed {
+      return Err(CommandError::InCharacter(format!(
+        "You'll have to close {} first.",
+        object.definite_name()
+      )));
+    }
+    object.locked = true;
+    game_state.enqueue_output("Locked.".to_string());
+    Ok(())
+  }
+}
+
+/// Make sure the player is holding a key that fits the object's lock, where
+/// `verb` is what they are trying to do with it (e.g. "lock").
+pub(crate) fn check_key(world: &World, id: &str, key_id: &str, verb: &str) -> Result<(), CommandError> {
+  let object = world
+    .object(id)
+    .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", id)))?;
+  let key = world
+    .object(key_id)
+    .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", key_id)))?;
+  if object.key.is_none() {
+    return Err(CommandError::InCharacter(format!(
+      "You can't {} {}.",
+      verb,
+      object.definite_name()
+    )));
+  }
+  if key.location != Location::Player {
+    return Err(CommandError::InCharacter(format!(
+      "You aren't holding {}.",
+      key.definite_name()
+    )));
+  }
+  if object.key.as_deref() != Some(key_id) {
+    return Err(CommandError::InCharacter(format!(
+      "The {} doesn't fit {}.",
+      key.name,
+      object.definite_name()
+    )));
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use crate::world::prelude::Object;
+
+  /// The test state, with a lockable mailbox whose key the player holds.
+  fn test_state() -> GameState {
+    let mut game_state = test_utils::test_state();
+    game_state.world = game_state
+      .world
+      .with_object("key", Object::new("small key", "").at(Location::Player));
+    let mailbox = game_state.world.object_mut("mailbox").unwrap();
+    mailbox.openable = true;
+    mailbox.key = Some("key".to_string());
+    game_state
+  }
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_state();
+    assert_eq!(
+      LockCommand::new("mailbox", "key").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "You'll have to close the small mailbox first.".to_string()
+      ))
+    );
+    game_state.world.object_mut("mailbox").unwrap().closed = true;
+    LockCommand::new("mailbox", "key").execute(&mut game_state)?;
+    assert!(game_state.world.object("mailbox").unwrap().locked);
+    assert_eq!(game_state.dequeue_output(), Some("Locked.".to_string()));
+    assert_eq!(
+      LockCommand::new("mailbox", "key").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "The small mailbox is already locked.".to_string()
+      ))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_check_key() {
+    test_utils::init();
+    let mut game_state = test_state();
+    let world = &mut game_state.world;
+    assert_eq!(
+      check_key(world, "mailbox", "lantern", "lock"),
+      Err(CommandError::InCharacter(
+        "The brass lantern doesn't fit the small mailbox.".to_string()
+      ))
+    );
+    assert_eq!(
+      check_key(world, "lantern", "key", "unlock"),
+      Err(CommandError::InCharacter(
+        "You can't unlock the brass lantern.".to_string()
+      ))
+    );
+    world.object_mut("key").unwrap().location = Location::Nowhere;
+    assert_eq!(
+      check_key(world, "mailbox", "key", "lock"),
+      Err(CommandError::InCharacter(
+        "You aren't holding the small key.".to_string()
+      ))
+    );
+  }
+}
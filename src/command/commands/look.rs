@@ -0,0 +1,42 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Look command, which describes the player's surroundings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LookCommand;
+
+impl LookCommand {
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let description = game_state
+      .describe_current_room()
+      .ok_or_else(|| CommandError::Unexpected("The player is not in a room.".to_string()))?;
+    game_state.enqueue_output(description);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    LookCommand.execute(&mut game_state)?;
+    let output = game_state.dequeue_output().unwrap();
+    assert!(output.starts_with("West of House\nYou are standing in an open field."));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_nowhere() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    let result = LookCommand.execute(&mut game_state);
+    assert!(matches!(result, Err(CommandError::Unexpected(_))));
+  }
+}
@@ -0,0 +1,95 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+
+/// Look in command, which lists the contents of a container.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookInCommand {
+  /// The container to look in.
+  pub container: ObjectId,
+}
+
+impl LookInCommand {
+  /// Create a new look in command.
+  pub fn new(container: &str) -> Self {
+    Self {
+      container: container.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let world = &game_state.world;
+    let container = world
+      .object(&self.container)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.container)))?;
+    if !container.container && !container.supporter {
+      return Err(CommandError::InCharacter(format!(
+        "You can't look inside {}.",
+        container.definite_name()
+      )));
+    }
+    if container.closed {
+      return Err(CommandError::InCharacter(format!("The {} is closed.", container.name)));
+    }
+    let output = world
+      .describe_contents(&self.container)
+      .unwrap_or_else(|| format!("The {} is empty.", container.name));
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    LookInCommand::new("mailbox").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("Inside the small mailbox is: a leaflet.".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_empty() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    LookInCommand::new("table").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("The wooden table is empty.".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_closed() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = LookInCommand::new("box").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter("The wooden box is closed.".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_execute_not_container() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = LookInCommand::new("lantern").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "You can't look inside the brass lantern.".to_string()
+      ))
+    );
+  }
+}
@@ -0,0 +1,100 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::{Location, ObjectId};
+
+/// Open command, which opens a door or container.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpenCommand {
+  /// The object to open.
+  pub object: ObjectId,
+}
+
+impl OpenCommand {
+  /// Create a new open command.
+  pub fn new(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let world = &mut game_state.world;
+    let object = world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if !object.openable {
+      return Err(CommandError::InCharacter(format!(
+        "You can't open {}.",
+        object.definite_name()
+      )));
+    }
+    if !object.closed {
+      return Err(CommandError::InCharacter(format!(
+        "The {} is already open.",
+        object.name
+      )));
+    }
+    if object.locked {
+      return Err(CommandError::InCharacter(format!("The {} is locked.", object.name)));
+    }
+    object.closed = false;
+    let name = object.definite_name();
+    let contents = world.list_objects(&world.contents(&Location::In(self.object.clone())));
+    let output = if contents.is_empty() {
+      "Opened.".to_string()
+    } else {
+      format!("Opening {} reveals {}.", name, contents.join(", "))
+    };
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let mailbox = game_state.world.object_mut("mailbox").unwrap();
+    mailbox.openable = true;
+    mailbox.closed = true;
+    OpenCommand::new("mailbox").execute(&mut game_state)?;
+    assert!(!game_state.world.object("mailbox").unwrap().closed);
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("Opening the small mailbox reveals a leaflet.".to_string())
+    );
+    assert_eq!(
+      OpenCommand::new("mailbox").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "The small mailbox is already open.".to_string()
+      ))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_unopenable_or_locked() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    assert_eq!(
+      OpenCommand::new("lantern").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "You can't open the brass lantern.".to_string()
+      ))
+    );
+    let mailbox = game_state.world.object_mut("mailbox").unwrap();
+    mailbox.openable = true;
+    mailbox.closed = true;
+    mailbox.locked = true;
+    assert_eq!(
+      OpenCommand::new("mailbox").execute(&mut game_state),
+      Err(CommandError::InCharacter("The small mailbox is locked.".to_string()))
+    );
+  }
+}
@@ -0,0 +1,207 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::{Location, ObjectId};
+
+/// Put command, which puts an object inside or on top of another object.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PutCommand {
+  /// The object to put somewhere.
+  pub object: ObjectId,
+  /// Where to put it; either `Location::In` or `Location::On`.
+  pub destination: Location,
+}
+
+impl PutCommand {
+  /// Create a new command to put an object inside another object.
+  pub fn new_in(object: &str, container: &str) -> Self {
+    Self {
+      object: object.to_string(),
+      destination: Location::In(container.to_string()),
+    }
+  }
+
+  /// Create a new command to put an object on top of another object.
+  pub fn new_on(object: &str, supporter: &str) -> Self {
+    Self {
+      object: object.to_string(),
+      destination: Location::On(supporter.to_string()),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let world = &game_state.world;
+    let object = world
+      .object(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    let (target_id, inside) = match &self.destination {
+      Location::In(id) => (id, true),
+      Location::On(id) => (id, false),
+      _ => {
+        return Err(CommandError::Unexpected(format!(
+          "Cannot put an object at {:?}.",
+          self.destination
+        )))
+      },
+    };
+    let target = world
+      .object(target_id)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", target_id)))?;
+    if object.location == self.destination {
+      return Err(CommandError::InCharacter(format!(
+        "The {} is already there.",
+        object.name
+      )));
+    }
+    if object.fixed {
+      return Err(CommandError::InCharacter(format!(
+        "You can't move {}.",
+        object.definite_name()
+      )));
+    }
+    if *target_id == self.object || world.is_within(target_id, &self.object) {
+      return Err(CommandError::InCharacter(
+        "You can't put something inside itself.".to_string(),
+      ));
+    }
+    if inside && !target.container {
+      return Err(CommandError::InCharacter(format!(
+        "You can't put anything in {}.",
+        target.definite_name()
+      )));
+    }
+    if !inside && !target.supporter {
+      return Err(CommandError::InCharacter(format!(
+        "You can't put anything on {}.",
+        target.definite_name()
+      )));
+    }
+    if inside && target.closed {
+      return Err(CommandError::InCharacter(format!("The {} is closed.", target.name)));
+    }
+    if let Some(capacity) = target.capacity {
+      if world.contents(&self.destination).len() >= capacity {
+        return Err(CommandError::InCharacter(format!(
+          "There's no more room {} {}.",
+          if inside { "in" } else { "on" },
+          target.definite_name()
+        )));
+      }
+    }
+    if let Some(object) = game_state.world.object_mut(&self.object) {
+      object.location = self.destination.clone();
+    }
+    game_state.enqueue_output("Done.".to_string());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use crate::world::prelude::Object;
+
+  #[test]
+  fn test_execute_in() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    PutCommand::new_in("lantern", "mailbox").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.world.object("lantern").unwrap().location,
+      Location::In("mailbox".to_string())
+    );
+    assert_eq!(game_state.dequeue_output(), Some("Done.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_on() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    PutCommand::new_on("lantern", "table").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.world.object("lantern").unwrap().location,
+      Location::On("table".to_string())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_closed() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = PutCommand::new_in("lantern", "box").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter("The wooden box is closed.".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_execute_wrong_kind() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = PutCommand::new_on("lantern", "mailbox").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "You can't put anything on the small mailbox.".to_string()
+      ))
+    );
+    let result = PutCommand::new_in("lantern", "table").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "You can't put anything in the wooden table.".to_string()
+      ))
+    );
+  }
+
+  #[test]
+  fn test_execute_capacity() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state
+      .world
+      .add_object("coin", Object::new("gold coin", "A coin.").at(Location::Player));
+    PutCommand::new_in("lantern", "mailbox").execute(&mut game_state)?;
+    let result = PutCommand::new_in("coin", "mailbox").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "There's no more room in the small mailbox.".to_string()
+      ))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_inside_itself() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world.object_mut("box").unwrap().closed = false;
+    game_state.world.object_mut("lantern").unwrap().location = Location::In("box".to_string());
+    game_state.world.object_mut("lantern").unwrap().container = true;
+    let result = PutCommand::new_in("box", "lantern").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "You can't put something inside itself.".to_string()
+      ))
+    );
+  }
+
+  #[test]
+  fn test_execute_fixed() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = PutCommand::new_on("mailbox", "table").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "You can't move the small mailbox.".to_string()
+      ))
+    );
+  }
+}
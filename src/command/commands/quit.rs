@@ -24,9 +24,9 @@ mod tests {
     test_utils::init();
     let mut game_state = GameState::default();
     let command = QuitCommand;
-    assert_eq!(game_state.quit_flag(), false);
+    assert!(!game_state.quit_flag());
     command.execute(&mut game_state)?;
-    assert_eq!(game_state.quit_flag(), true);
+    assert!(game_state.quit_flag());
     Ok(())
   }
 }
@@ -0,0 +1,103 @@
+use super::save::validate_name;
+use crate::command::prelude::CommandError;
+use crate::game::prelude::{GameState, SaveFile};
+
+/// Restore command, which restores a saved game from disk.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestoreCommand {
+  /// The name of the saved game.
+  pub name: String,
+}
+
+impl RestoreCommand {
+  /// Create a new restore command.
+  pub fn new(name: &str) -> Self {
+    Self { name: name.to_string() }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    validate_name(&self.name)?;
+    let save_file = SaveFile::read(&game_state.save_path(&self.name))?;
+    game_state.restore(save_file.state);
+    // The snapshots belong to the game that was left, so they can't be undone
+    // into.
+    game_state.undo_history.clear();
+    game_state.enqueue_output(format!("Restored '{}'.", self.name));
+    if let Some(description) = game_state.describe_current_room() {
+      game_state.enqueue_output(description);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::command::prelude::{SaveCommand, UndoCommand};
+  use crate::game::prelude::UndoSnapshot;
+  use crate::test_utils;
+  use crate::world::prelude::Location;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let mut game_state = test_utils::test_state();
+    game_state.save_directory = directory.path().to_path_buf();
+    SaveCommand::new("game").execute(&mut game_state)?;
+    game_state.world.player_location = "north_of_house".to_string();
+    game_state.world.object_mut("lantern").unwrap().location = Location::Nowhere;
+    game_state.output_queue.clear();
+    RestoreCommand::new("game").execute(&mut game_state)?;
+    assert_eq!(game_state.world, test_utils::test_world());
+    assert_eq!(game_state.save_directory, directory.path());
+    assert_eq!(game_state.dequeue_output(), Some("Restored 'game'.".to_string()));
+    assert!(game_state.dequeue_output().unwrap().starts_with("West of House"));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_clears_undo_history() -> Result<(), CommandError> {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let mut game_state = test_utils::test_state();
+    game_state.save_directory = directory.path().to_path_buf();
+    SaveCommand::new("game").execute(&mut game_state)?;
+    let snapshot = UndoSnapshot {
+      label: "n".to_string(),
+      state: game_state.snapshot(),
+    };
+    game_state.push_undo_snapshot(snapshot, 5);
+    RestoreCommand::new("game").execute(&mut game_state)?;
+    assert_eq!(
+      UndoCommand::new(1).execute(&mut game_state),
+      Err(CommandError::OutOfCharacter("There is nothing to undo.".to_string()))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_missing() {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let mut game_state = test_utils::test_state();
+    game_state.save_directory = directory.path().to_path_buf();
+    let result = RestoreCommand::new("game").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::OutOfCharacter(
+        "There is no saved game by that name.".to_string()
+      ))
+    );
+  }
+
+  #[test]
+  fn test_execute_invalid_name() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = RestoreCommand::new("/etc/passwd").execute(&mut game_state);
+    assert!(matches!(result, Err(CommandError::OutOfCharacter(_))));
+  }
+}
@@ -0,0 +1,70 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::{GameState, SaveFile};
+
+/// Save command, which saves the game to disk.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SaveCommand {
+  /// The name of the saved game.
+  pub name: String,
+}
+
+impl SaveCommand {
+  /// Create a new save command.
+  pub fn new(name: &str) -> Self {
+    Self { name: name.to_string() }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    validate_name(&self.name)?;
+    SaveFile::new(game_state).write(&game_state.save_path(&self.name))?;
+    game_state.enqueue_output(format!("Saved as '{}'.", self.name));
+    Ok(())
+  }
+}
+
+/// Make sure the name of a saved game is safe to use as a file name.
+pub(crate) fn validate_name(name: &str) -> Result<(), CommandError> {
+  if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+    return Err(CommandError::OutOfCharacter(
+      "Saved games may only be named with letters, numbers, dashes, and underscores.".to_string(),
+    ));
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let mut game_state = test_utils::test_state();
+    game_state.save_directory = directory.path().to_path_buf();
+    SaveCommand::new("game").execute(&mut game_state)?;
+    assert!(directory.path().join("game.sav").exists());
+    assert_eq!(game_state.dequeue_output(), Some("Saved as 'game'.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_invalid_name() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = SaveCommand::new("../game").execute(&mut game_state);
+    assert!(matches!(result, Err(CommandError::OutOfCharacter(_))));
+  }
+
+  #[test]
+  fn test_execute_unwritable() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.save_directory = "/nonexistent/directory".into();
+    let result = SaveCommand::new("game").execute(&mut game_state);
+    assert!(matches!(result, Err(CommandError::OutOfCharacter(_))));
+  }
+}
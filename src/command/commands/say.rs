@@ -0,0 +1,60 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Say command, which says (or yells) something out loud.
+///
+/// Nothing responds unless the game reacts to the phrase; see
+/// [`Parser::add_phrase`](crate::parser::prelude::Parser::add_phrase).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SayCommand {
+  /// What to say, as the player typed it.
+  pub text: String,
+  /// Whether to yell it rather than say it.
+  pub yell: bool,
+}
+
+impl SayCommand {
+  /// Create a new command to say something.
+  pub fn new(text: &str) -> Self {
+    Self {
+      text: text.to_string(),
+      yell: false,
+    }
+  }
+
+  /// Create a new command to yell something.
+  pub fn new_yell(text: &str) -> Self {
+    Self {
+      text: text.to_string(),
+      yell: true,
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let verb = if self.yell { "yell" } else { "say" };
+    game_state.enqueue_output(format!("You {}, \"{}\"", verb, self.text));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    SayCommand::new("Hi.").execute(&mut game_state)?;
+    SayCommand::new_yell("Hello, Sailor!").execute(&mut game_state)?;
+    assert_eq!(game_state.dequeue_output(), Some("You say, \"Hi.\"".to_string()));
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You yell, \"Hello, Sailor!\"".to_string())
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,116 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+
+/// Switch command, which switches a light source on or off.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchCommand {
+  /// The object to switch.
+  pub object: ObjectId,
+  /// Whether to switch it on, rather than off.
+  pub on: bool,
+}
+
+impl SwitchCommand {
+  /// Create a new command to switch an object on.
+  pub fn new_on(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+      on: true,
+    }
+  }
+
+  /// Create a new command to switch an object off.
+  pub fn new_off(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+      on: false,
+    }
+  }
+
+  /// Execute the command.
+  ///
+  /// If this lights up or darkens the room, the player is told what they
+  /// can now see.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let state = if self.on { "on" } else { "off" };
+    let was_lit = game_state.world.is_lit();
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if !object.light_source {
+      return Err(CommandError::InCharacter(format!(
+        "You can't turn {} {}.",
+        state,
+        object.definite_name()
+      )));
+    }
+    if object.lit == self.on {
+      return Err(CommandError::InCharacter(format!(
+        "The {} is already {}.",
+        object.name, state
+      )));
+    }
+    object.lit = self.on;
+    let output = format!("The {} is now {}.", object.name, state);
+    game_state.enqueue_output(output);
+    match (was_lit, game_state.world.is_lit()) {
+      (true, false) => game_state.enqueue_output("It is now pitch black.".to_string()),
+      (false, true) => {
+        if let Some(description) = game_state.describe_current_room() {
+          game_state.enqueue_output(description);
+        }
+      },
+      _ => {},
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world.object_mut("lantern").unwrap().light_source = true;
+    SwitchCommand::new_on("lantern").execute(&mut game_state)?;
+    assert!(game_state.world.object("lantern").unwrap().lit);
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("The brass lantern is now on.".to_string())
+    );
+    assert_eq!(
+      SwitchCommand::new_on("lantern").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "The brass lantern is already on.".to_string()
+      ))
+    );
+    assert_eq!(
+      SwitchCommand::new_off("mailbox").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "You can't turn off the small mailbox.".to_string()
+      ))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_in_the_dark() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world.rooms.get_mut("west_of_house").unwrap().dark = true;
+    game_state.world.object_mut("lantern").unwrap().light_source = true;
+    SwitchCommand::new_on("lantern").execute(&mut game_state)?;
+    game_state.dequeue_output();
+    assert_eq!(game_state.dequeue_output(), game_state.world.describe_current_room());
+    SwitchCommand::new_off("lantern").execute(&mut game_state)?;
+    game_state.dequeue_output();
+    assert_eq!(game_state.dequeue_output(), Some("It is now pitch black.".to_string()));
+    Ok(())
+  }
+}
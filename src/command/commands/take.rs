@@ -0,0 +1,79 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::{Location, ObjectId};
+
+/// Take command, which picks up an object.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TakeCommand {
+  /// The object to take.
+  pub object: ObjectId,
+}
+
+impl TakeCommand {
+  /// Create a new take command.
+  pub fn new(object: &str) -> Self {
+    Self {
+      object: object.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if object.location == Location::Player {
+      return Err(CommandError::InCharacter("You already have that!".to_string()));
+    }
+    if object.fixed {
+      return Err(CommandError::InCharacter(format!(
+        "You can't take {}.",
+        object.definite_name()
+      )));
+    }
+    object.location = Location::Player;
+    game_state.enqueue_output("Taken.".to_string());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    TakeCommand::new("leaflet").execute(&mut game_state)?;
+    assert_eq!(game_state.world.object("leaflet").unwrap().location, Location::Player);
+    assert_eq!(game_state.dequeue_output(), Some("Taken.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_already_carried() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = TakeCommand::new("lantern").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter("You already have that!".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_execute_fixed() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let result = TakeCommand::new("mailbox").execute(&mut game_state);
+    assert_eq!(
+      result,
+      Err(CommandError::InCharacter(
+        "You can't take the small mailbox.".to_string()
+      ))
+    );
+  }
+}
@@ -29,7 +29,7 @@ mod tests {
       error: CommandError::InCharacter("Test error.".to_string()),
     };
     let result = command.execute(&mut game_state);
-    assert_eq!(result.is_err(), true);
+    assert!(result.is_err());
     assert_eq!(result.unwrap_err().to_string(), "Test error.");
     Ok(())
   }
@@ -0,0 +1,48 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Time command, which tells the player the time and how many turns they
+/// have taken.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeCommand;
+
+impl TimeCommand {
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let clock = &game_state.world.clock;
+    let output = format!(
+      "It is {} on day {}. You have taken {} {}.",
+      clock.time_of_day(),
+      clock.day(),
+      clock.turns,
+      if clock.turns == 1 { "turn" } else { "turns" }
+    );
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    TimeCommand.execute(&mut game_state)?;
+    game_state.world.clock.tick();
+    TimeCommand.execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("It is 9:00 am on day 1. You have taken 0 turns.".to_string())
+    );
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("It is 9:01 am on day 1. You have taken 1 turn.".to_string())
+    );
+    Ok(())
+  }
+}
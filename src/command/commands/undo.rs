@@ -0,0 +1,139 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use std::mem;
+
+/// Undo command, which restores the game state from before one or more
+/// previous commands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UndoCommand {
+  /// The number of commands to undo.
+  pub levels: usize,
+}
+
+impl UndoCommand {
+  /// Create a new undo command.
+  pub fn new(levels: usize) -> Self {
+    Self { levels }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    if self.levels == 0 {
+      return Err(CommandError::OutOfCharacter("You can't undo nothing.".to_string()));
+    }
+    let mut undone = Vec::new();
+    let mut restored = None;
+    while undone.len() < self.levels {
+      match game_state.pop_undo_snapshot() {
+        Some(snapshot) => {
+          undone.push(snapshot.label);
+          restored = Some(snapshot.state);
+        },
+        None => break,
+      }
+    }
+    let restored = restored.ok_or_else(|| CommandError::OutOfCharacter("There is nothing to undo.".to_string()))?;
+    // Aliases belong to the player rather than to the story, so they stay.
+    let aliases = mem::take(&mut game_state.aliases);
+    game_state.restore(restored);
+    game_state.aliases = aliases;
+    for label in undone {
+      game_state.enqueue_output(format!("[Undone: {}]", label));
+    }
+    if let Some(description) = game_state.describe_current_room() {
+      game_state.enqueue_output(description);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::{GameState, UndoSnapshot};
+  use crate::test_utils;
+
+  /// Move the player to a room, taking a snapshot beforehand.
+  fn move_player(game_state: &mut GameState, room: &str) {
+    let snapshot = UndoSnapshot {
+      label: format!("go to {}", room),
+      state: game_state.snapshot(),
+    };
+    game_state.push_undo_snapshot(snapshot, 10);
+    game_state.world.player_location = room.to_string();
+  }
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    move_player(&mut game_state, "north_of_house");
+    UndoCommand::new(1).execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "west_of_house");
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("[Undone: go to north_of_house]".to_string())
+    );
+    assert!(game_state.dequeue_output().unwrap().starts_with("West of House"));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_repeatedly() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    move_player(&mut game_state, "north_of_house");
+    move_player(&mut game_state, "west_of_house");
+    move_player(&mut game_state, "north_of_house");
+    UndoCommand::new(1).execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "west_of_house");
+    UndoCommand::new(1).execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "north_of_house");
+    UndoCommand::new(1).execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "west_of_house");
+    assert!(UndoCommand::new(1).execute(&mut game_state).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_multiple_levels() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    move_player(&mut game_state, "north_of_house");
+    move_player(&mut game_state, "nowhere");
+    UndoCommand::new(5).execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "west_of_house");
+    assert_eq!(game_state.dequeue_output(), Some("[Undone: go to nowhere]".to_string()));
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("[Undone: go to north_of_house]".to_string())
+    );
+    assert!(game_state.undo_history.is_empty());
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_keeps_aliases() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    move_player(&mut game_state, "north_of_house");
+    game_state
+      .aliases
+      .insert("kk".to_string(), vec!["kill kobold".to_string()]);
+    UndoCommand::new(1).execute(&mut game_state)?;
+    assert_eq!(game_state.world.player_location, "west_of_house");
+    assert!(game_state.aliases.contains_key("kk"));
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_nothing_to_undo() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    assert_eq!(
+      UndoCommand::new(1).execute(&mut game_state),
+      Err(CommandError::OutOfCharacter("There is nothing to undo.".to_string()))
+    );
+    assert!(UndoCommand::new(0).execute(&mut game_state).is_err());
+  }
+}
@@ -0,0 +1,65 @@
+use super::lock::check_key;
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+
+/// Unlock command, which unlocks a door or container with a key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnlockCommand {
+  /// The object to unlock.
+  pub object: ObjectId,
+  /// The key to unlock it with.
+  pub key: ObjectId,
+}
+
+impl UnlockCommand {
+  /// Create a new unlock command.
+  pub fn new(object: &str, key: &str) -> Self {
+    Self {
+      object: object.to_string(),
+      key: key.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    check_key(&game_state.world, &self.object, &self.key, "unlock")?;
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if !object.locked {
+      return Err(CommandError::InCharacter(format!("The {} isn't locked.", object.name)));
+    }
+    object.locked = false;
+    game_state.enqueue_output("Unlocked.".to_string());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use crate::world::prelude::{Location, Object};
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world = game_state
+      .world
+      .with_object("key", Object::new("small key", "").at(Location::Player));
+    let mailbox = game_state.world.object_mut("mailbox").unwrap();
+    *mailbox = mailbox.clone().openable().with_key("key").locked();
+    UnlockCommand::new("mailbox", "key").execute(&mut game_state)?;
+    assert!(!game_state.world.object("mailbox").unwrap().locked);
+    assert!(game_state.world.object("mailbox").unwrap().closed);
+    assert_eq!(game_state.dequeue_output(), Some("Unlocked.".to_string()));
+    assert_eq!(
+      UnlockCommand::new("mailbox", "key").execute(&mut game_state),
+      Err(CommandError::InCharacter("The small mailbox isn't locked.".to_string()))
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,30 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+
+/// Wait command, which lets time pass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaitCommand;
+
+impl WaitCommand {
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    game_state.enqueue_output("Time passes.".to_string());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::prelude::GameState;
+  use crate::test_utils;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    WaitCommand.execute(&mut game_state)?;
+    assert_eq!(game_state.dequeue_output(), Some("Time passes.".to_string()));
+    Ok(())
+  }
+}
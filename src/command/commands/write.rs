@@ -0,0 +1,76 @@
+use crate::command::prelude::CommandError;
+use crate::game::prelude::GameState;
+use crate::world::prelude::ObjectId;
+
+/// Write command, which writes something on an object.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WriteCommand {
+  /// What to write, as the player typed it.
+  pub text: String,
+  /// The object to write on.
+  pub object: ObjectId,
+}
+
+impl WriteCommand {
+  /// Create a new write command.
+  pub fn new(text: &str, object: &str) -> Self {
+    Self {
+      text: text.to_string(),
+      object: object.to_string(),
+    }
+  }
+
+  /// Execute the command.
+  pub fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+    let object = game_state
+      .world
+      .object_mut(&self.object)
+      .ok_or_else(|| CommandError::Unexpected(format!("No such object '{}'.", self.object)))?;
+    if !object.writable {
+      return Err(CommandError::InCharacter(format!(
+        "You can't write on {}.",
+        object.definite_name()
+      )));
+    }
+    object.writing.push(self.text.clone());
+    let output = format!("You write \"{}\" on {}.", self.text, object.definite_name());
+    game_state.enqueue_output(output);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use crate::world::prelude::Object;
+
+  #[test]
+  fn test_execute() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state
+      .world
+      .objects
+      .insert("paper".to_string(), Object::new("paper", "").writable());
+    WriteCommand::new("XYZZY", "paper").execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You write \"XYZZY\" on the paper.".to_string())
+    );
+    assert_eq!(game_state.world.object("paper").unwrap().writing, vec!["XYZZY"]);
+    Ok(())
+  }
+
+  #[test]
+  fn test_execute_not_writable() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    assert_eq!(
+      WriteCommand::new("XYZZY", "lantern").execute(&mut game_state),
+      Err(CommandError::InCharacter(
+        "You can't write on the brass lantern.".to_string()
+      ))
+    );
+  }
+}
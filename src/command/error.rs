@@ -19,4 +19,8 @@ pub enum CommandError {
   /// An out-of-character error occurred (e.g. "You can't do that here.").
   #[error("{0}")]
   OutOfCharacter(String),
+  /// The player died (e.g. "The troll's axe removes your head."), which ends
+  /// the game.
+  #[error("{0}")]
+  Death(String),
 }
@@ -0,0 +1,157 @@
+use crate::command::prelude::{Command, CommandError};
+use crate::game::prelude::GameState;
+use crate::parser::prelude::{Action, ParserError};
+use crate::world::prelude::ObjectId;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// A command defined outside saltshore (e.g. by a game built on it).
+///
+/// Custom commands are compared by identity: two commands are equal only if
+/// they are the same command, since their data can't be seen.
+pub trait CustomCommand: fmt::Debug + Send + Sync {
+  /// Execute the command.
+  fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError>;
+
+  /// The objects the command acts upon, which later pronouns (e.g. "it")
+  /// refer to.
+  fn objects(&self) -> Vec<ObjectId> {
+    Vec::new()
+  }
+
+  /// Whether the command takes game time, letting the world move on.
+  fn takes_time(&self) -> bool {
+    true
+  }
+}
+
+impl PartialEq for dyn CustomCommand {
+  fn eq(&self, other: &Self) -> bool {
+    std::ptr::addr_eq(self, other)
+  }
+}
+
+/// The definition of a command: the verbs that invoke it, and how to turn an
+/// action using one of those verbs into a command.
+pub trait CommandDefinition: fmt::Debug + Send + Sync {
+  /// The verbs that invoke the command (e.g. `["dig"]`).
+  fn verbs(&self) -> Vec<String>;
+
+  /// Parse an action using one of the verbs into a command.
+  ///
+  /// The command need not be a custom command; a definition can also produce
+  /// one of the built-in commands.
+  fn parse(&self, action: &Action, game_state: &GameState) -> Result<Command, ParserError>;
+}
+
+/// A registry of command definitions, keyed by verb.
+///
+/// Registered verbs take precedence over the built-in verbs.
+#[derive(Clone, Debug, Default)]
+pub struct CommandRegistry {
+  /// The definitions, keyed by verb.
+  definitions: BTreeMap<String, Arc<dyn CommandDefinition>>,
+}
+
+impl CommandRegistry {
+  /// Create a new, empty registry.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register a command definition under each of its verbs, replacing any
+  /// definition previously registered for the same verb.
+  pub fn register(&mut self, definition: impl CommandDefinition + 'static) {
+    let definition: Arc<dyn CommandDefinition> = Arc::new(definition);
+    for verb in definition.verbs() {
+      self.definitions.insert(verb.to_lowercase(), Arc::clone(&definition));
+    }
+  }
+
+  /// The registered verbs.
+  pub fn verbs(&self) -> impl Iterator<Item = String> + '_ {
+    self.definitions.keys().cloned()
+  }
+
+  /// Look up the definition registered for a verb.
+  pub fn get(&self, verb: &str) -> Option<&dyn CommandDefinition> {
+    self.definitions.get(verb).map(Arc::as_ref)
+  }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  /// A command that tells the player they dug a hole.
+  #[derive(Debug)]
+  pub(crate) struct DigCommand {
+    /// What the player is digging with, if anything.
+    pub(crate) tool: Option<String>,
+  }
+
+  impl CustomCommand for DigCommand {
+    fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+      match &self.tool {
+        Some(tool) => game_state.enqueue_output(format!("You dig a hole with the {}.", tool)),
+        None => game_state.enqueue_output("You dig a hole with your bare hands.".to_string()),
+      }
+      Ok(())
+    }
+  }
+
+  /// The definition of the dig command.
+  #[derive(Debug)]
+  pub(crate) struct Dig;
+
+  impl CommandDefinition for Dig {
+    fn verbs(&self) -> Vec<String> {
+      vec!["dig".to_string(), "excavate".to_string()]
+    }
+
+    fn parse(&self, action: &Action, _game_state: &GameState) -> Result<Command, ParserError> {
+      match (action.preposition.as_deref(), &action.indirect_object) {
+        (None, None) => Ok(Command::Custom(Arc::new(DigCommand { tool: None }))),
+        (Some("with"), Some(tool)) => Ok(Command::Custom(Arc::new(DigCommand {
+          tool: Some(tool.to_string()),
+        }))),
+        _ => Err(ParserError::InvalidInput(action.verb.clone())),
+      }
+    }
+  }
+
+  #[test]
+  fn test_register() {
+    test_utils::init();
+    let mut registry = CommandRegistry::new();
+    registry.register(Dig);
+    assert!(registry.get("dig").is_some());
+    assert!(registry.get("excavate").is_some());
+    assert!(registry.get("take").is_none());
+  }
+
+  #[test]
+  fn test_custom_command() -> Result<(), CommandError> {
+    test_utils::init();
+    let mut registry = CommandRegistry::new();
+    registry.register(Dig);
+    let mut game_state = GameState::default();
+    let action = Action::parse("dig with shovel").unwrap();
+    let command = registry.get("dig").unwrap().parse(&action, &game_state).unwrap();
+    let expected = Command::Custom(Arc::new(DigCommand {
+      tool: Some("shovel".to_string()),
+    }));
+    assert_eq!(format!("{:?}", command), format!("{:?}", expected));
+    // Custom commands are only equal to themselves, whatever their data.
+    assert_eq!(command, command.clone());
+    assert_ne!(command, expected);
+    command.execute(&mut game_state)?;
+    assert_eq!(
+      game_state.dequeue_output(),
+      Some("You dig a hole with the shovel.".to_string())
+    );
+    Ok(())
+  }
+}
@@ -1,13 +1,34 @@
+/// Fighting and getting hurt.
+pub mod combat;
 /// An error type for the game.
 pub mod error;
 /// The game loop.
 pub mod r#loop;
+/// Non-player characters.
+pub mod npc;
+/// Saving and restoring the game state.
+pub mod save;
+/// Timed events.
+pub mod scheduler;
 /// The game state.
 pub mod state;
 
 /// The game prelude.
 pub mod prelude {
+  pub use crate::game::combat::Combatant;
   pub use crate::game::error::GameError;
+  pub use crate::game::npc::Appointment;
+  pub use crate::game::npc::Behavior;
+  pub use crate::game::npc::Npc;
+  pub use crate::game::npc::NpcAction;
+  pub use crate::game::npc::NpcId;
   pub use crate::game::r#loop::GameLoop;
+  pub use crate::game::save::SaveFile;
+  pub use crate::game::scheduler::ScheduledEvent;
+  pub use crate::game::scheduler::Scheduler;
+  pub use crate::game::scheduler::Timer;
+  pub use crate::game::state::FailedInput;
   pub use crate::game::state::GameState;
+  pub use crate::game::state::PendingQuestion;
+  pub use crate::game::state::UndoSnapshot;
 }
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of hit points the player starts with by default.
+pub const DEFAULT_PLAYER_HIT_POINTS: u32 = 10;
+
+/// The damage done by a bare-handed blow.
+pub const UNARMED_DAMAGE: u32 = 1;
+
+/// Something that can fight and be hurt: the player or a non-player
+/// character.
+///
+/// Combat is deterministic. Each blow does its damage less the target's
+/// armor, but always at least one hit point, so that every fight ends.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Combatant {
+  /// The hit points left; at zero, the combatant is dead.
+  pub hit_points: u32,
+  /// The hit points the combatant started with.
+  pub max_hit_points: u32,
+  /// The damage the combatant does without a weapon, or with its natural
+  /// weapons (e.g. a troll's axe).
+  pub damage: u32,
+  /// The damage the combatant turns aside from each blow.
+  pub armor: u32,
+}
+
+impl Combatant {
+  /// Create a new combatant at full health.
+  pub fn new(hit_points: u32, damage: u32, armor: u32) -> Self {
+    Self {
+      hit_points,
+      max_hit_points: hit_points,
+      damage,
+      armor,
+    }
+  }
+
+  /// Whether the combatant is dead.
+  pub fn is_dead(&self) -> bool {
+    self.hit_points == 0
+  }
+
+  /// Take a blow of the specified damage, with the specified armor (in
+  /// addition to the combatant's own), returning the hit points lost.
+  pub fn take_hit(&mut self, damage: u32, armor: u32) -> u32 {
+    let lost = damage.saturating_sub(self.armor + armor).max(1).min(self.hit_points);
+    self.hit_points -= lost;
+    lost
+  }
+}
+
+impl Default for Combatant {
+  fn default() -> Self {
+    Self::new(DEFAULT_PLAYER_HIT_POINTS, UNARMED_DAMAGE, 0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_take_hit() {
+    test_utils::init();
+    let mut combatant = Combatant::new(5, 1, 1);
+    assert_eq!(combatant.take_hit(3, 0), 2);
+    assert_eq!(combatant.take_hit(3, 2), 1);
+    assert_eq!(combatant.hit_points, 2);
+    assert!(!combatant.is_dead());
+    assert_eq!(combatant.take_hit(10, 0), 2);
+    assert!(combatant.is_dead());
+    assert_eq!(combatant.max_hit_points, 5);
+  }
+}
@@ -2,6 +2,7 @@ use crate::command::error::CommandError;
 use crate::input::error::InputError;
 use crate::output::error::OutputError;
 use crate::parser::error::ParserError;
+use crate::world::error::WorldError;
 use thiserror::Error as ThisError;
 
 /// Any error that can occur when running the game.
@@ -19,4 +20,7 @@ pub enum GameError {
   /// An error that can occur when executing a command.
   #[error("An error occurred while executing a command: {0}")]
   CommandError(#[from] CommandError),
+  /// An error that can occur when loading the game world.
+  #[error("An error occurred while loading the game world: {0}")]
+  WorldError(#[from] WorldError),
 }
@@ -1,12 +1,22 @@
+use crate::command::prelude::Command;
+use crate::command::prelude::CommandDefinition;
+use crate::command::prelude::CommandError;
+use crate::command::prelude::{RestoreCommand, UndoCommand};
 use crate::game::error::GameError;
-use crate::game::state::GameState;
+use crate::game::npc::Npc;
+use crate::game::state::{FailedInput, GameState, PendingQuestion, UndoSnapshot};
 use crate::input::prelude::InputReader;
 use crate::input::prelude::StdinReader;
 use crate::output::prelude::OutputWriter;
 use crate::output::prelude::StdoutWriter;
-use crate::parser::prelude::Parser;
+use crate::parser::prelude::{Parser, ParserError};
+use crate::parser::spelling;
+use crate::world::prelude::{ObjectId, World};
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, StdinLock, Stdout, Write};
+use std::mem;
+use std::path::PathBuf;
 
 /// The game loop.
 ///
@@ -25,13 +35,124 @@ where
   output: OutputWriter<W>,
   /// The parser.
   parser: Parser,
+  /// The world file to load during setup, if any.
+  world_file: Option<PathBuf>,
+  /// The input from which the current commands were parsed.
+  current_input: String,
+  /// The game state before the current input, which becomes an undo
+  /// snapshot once a command from that input has succeeded.
+  input_snapshot: Option<GameState>,
+  /// The maximum number of commands that can be undone.
+  undo_limit: usize,
+  /// The game state at the start of the game, which restarting returns to.
+  initial_state: Option<GameState>,
+  /// The number of inputs at the front of the input queue that came from
+  /// expanding an alias or macro, and so aren't expanded again.
+  expanded_inputs: usize,
+  /// The commands to run when scheduled events fire, keyed by the events'
+  /// names.
+  events: BTreeMap<String, Command>,
 }
 
+/// The default maximum number of commands that can be undone.
+pub const DEFAULT_UNDO_LIMIT: usize = 20;
+
+/// What the player is told when they die, unless the world has a "death"
+/// message.
+pub const DEFAULT_DEATH_MESSAGE: &str = "    ****  You have died  ****";
+
+/// What the player is asked once the game has ended.
+pub const END_OF_GAME_QUESTION: &str =
+  "Would you like to RESTART, RESTORE a saved game (e.g. RESTORE MYGAME), UNDO your last move, or QUIT?";
+
 impl<R, W> GameLoop<R, W>
 where
   R: BufRead,
   W: Write,
 {
+  /// Create a new game loop with the specified input and output.
+  pub fn new(input: InputReader<R>, output: OutputWriter<W>) -> Self {
+    GameLoop {
+      state: GameState::default(),
+      input,
+      output,
+      parser: Parser::new(),
+      world_file: None,
+      current_input: String::new(),
+      expanded_inputs: 0,
+      events: BTreeMap::new(),
+      input_snapshot: None,
+      undo_limit: DEFAULT_UNDO_LIMIT,
+      initial_state: None,
+    }
+  }
+
+  /// Load the game world from a world file during setup.
+  ///
+  /// See [`World::from_toml`] for a description of the format.
+  pub fn with_world_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+    self.world_file = Some(path.into());
+    self
+  }
+
+  /// Register a command definition, so that games can add their own verbs.
+  pub fn with_command(mut self, definition: impl CommandDefinition + 'static) -> Self {
+    self.parser.register(definition);
+    self
+  }
+
+  /// React to the player saying or yelling a phrase (e.g. "Hello, Sailor!")
+  /// by running the command instead.
+  pub fn with_phrase(mut self, phrase: &str, command: Command) -> Self {
+    self.parser.add_phrase(phrase, command);
+    self
+  }
+
+  /// Run the command whenever the scheduled event of the specified name
+  /// fires (e.g. when the bomb's fuse runs out); see
+  /// [`Scheduler`](crate::game::prelude::Scheduler).
+  pub fn with_event(mut self, name: &str, command: Command) -> Self {
+    self.events.insert(name.to_string(), command);
+    self
+  }
+
+  /// Add a non-player character, who acts on their own each turn.
+  pub fn with_npc(mut self, id: &str, npc: Npc) -> Self {
+    self.state.npcs.insert(id.to_string(), npc);
+    self
+  }
+
+  /// Add a synonym for a verb (e.g. "grab" for "take").
+  pub fn with_synonym(mut self, synonym: &str, verb: &str) -> Self {
+    self.parser.add_synonym(synonym, verb);
+    self
+  }
+
+  /// Correct unknown words automatically when there is a likely correction,
+  /// rather than just suggesting it.
+  pub fn with_autocorrect(mut self, autocorrect: bool) -> Self {
+    self.parser.set_autocorrect(autocorrect);
+    self
+  }
+
+  /// Limit the number of commands that can be undone.
+  pub fn with_undo_limit(mut self, undo_limit: usize) -> Self {
+    self.undo_limit = undo_limit;
+    self
+  }
+
+  /// Keep saved games in the specified directory.
+  pub fn with_save_directory<P: Into<PathBuf>>(mut self, path: P) -> Self {
+    self.state.save_directory = path.into();
+    self
+  }
+
+  /// Replace the game world.
+  pub fn with_world(mut self, world: World) -> Self {
+    self.state.world = world;
+    self
+  }
+
   /// The actual game loop.
   pub fn run(&mut self) -> Result<(), GameError> {
     self.setup()?;
@@ -44,8 +165,10 @@ where
   pub fn run_inner(&mut self) -> Result<(), GameError> {
     while !self.is_finished() {
       self.process_output()?;
-      self.process_input()?;
-      self.update()?;
+      // Meta commands (e.g. "look" or "save") don't let the world move on.
+      if self.process_input()? {
+        self.update()?;
+      }
     }
     Ok(())
   }
@@ -57,32 +180,46 @@ where
 
   /// Initialize game world, load assets, etc.
   fn setup(&mut self) -> Result<(), GameError> {
+    if let Some(path) = &self.world_file {
+      self.state.world = World::load(path)?;
+    }
+    self.initial_state = Some(self.state.snapshot());
+    if let Some(intro) = self.state.world.message("intro") {
+      self.state.enqueue_output(intro.to_string());
+    }
+    if let Some(description) = self.state.describe_current_room() {
+      self.state.enqueue_output(description);
+    }
     Ok(())
   }
 
   /// Handle player commands.
-  fn process_input(&mut self) -> Result<(), GameError> {
+  ///
+  /// Returns whether game time passed, which it does if the command that
+  /// was executed takes time.
+  fn process_input(&mut self) -> Result<bool, GameError> {
     // We will loop until we've successfully executed a command.
     let mut command_executed = false;
+    let mut took_time = false;
     while !command_executed {
       while self.state.command_queue.is_empty() {
         while self.state.input_queue.is_empty() {
           self.output.prompt()?;
           match self.input.read_inputs()? {
-            Some(inputs) => self.state.enqueue_inputs(inputs),
+            Some(inputs) => {
+              self.expanded_inputs = 0;
+              self.state.enqueue_inputs(inputs);
+            },
             None => {
               // EOF
               self.state.set_quit_flag(true);
-              return Ok(());
+              return Ok(false);
             },
           }
         }
         if let Some(input) = self.state.dequeue_input() {
-          if let Ok(command) = self.parser.parse(&input) {
-            self.state.enqueue_command(command);
-          } else {
-            self.handle_invalid_input(&input)?;
-          }
+          let input = self.expand_alias(input);
+          self.handle_input(input)?;
         } else {
           self.state.clear_input_and_command_queues();
         }
@@ -90,27 +227,245 @@ where
       if let Some(command) = self.state.dequeue_command() {
         let result = command.execute(&mut self.state);
         command_executed = result.is_ok();
-        if let Err(error) = result {
-          self.output.writeln(&format!("Error: {}", error))?;
-          self.state.clear_input_and_command_queues();
+        match result {
+          Ok(()) => {
+            took_time = command.takes_time();
+            // Undoing returns to before the input, however many commands it
+            // produced. Only turns are undone, so meta commands (e.g. "look")
+            // don't use up the undo history.
+            let snapshot = (command.is_undoable() && took_time)
+              .then(|| self.input_snapshot.take())
+              .flatten();
+            if command.is_repeatable() {
+              self.state.last_command = Some(command);
+            }
+            if let Some(state) = snapshot {
+              let label = self.current_input.clone();
+              self
+                .state
+                .push_undo_snapshot(UndoSnapshot { label, state }, self.undo_limit);
+            }
+          },
+          Err(CommandError::Death(message)) => {
+            // Undoing returns to before the fatal input.
+            if let Some(state) = self.input_snapshot.take() {
+              let label = self.current_input.clone();
+              self
+                .state
+                .push_undo_snapshot(UndoSnapshot { label, state }, self.undo_limit);
+            }
+            self.end_game(&message)?;
+            return Ok(false);
+          },
+          Err(error) => {
+            self.handle_command_error(&error)?;
+            self.state.clear_input_and_command_queues();
+          },
         }
       } else {
         self.state.clear_input_and_command_queues();
       }
     }
+    Ok(took_time)
+  }
+
+  /// Expand an input that starts with an alias or macro, queueing all but
+  /// the first of the inputs it stands for.
+  ///
+  /// A macro's body is split into inputs here, as if it had been typed.
+  /// Expansions aren't expanded again, so a macro can use a verb of the same
+  /// name (e.g. `macro dig = "take shovel; dig; drop shovel"`).
+  fn expand_alias(&mut self, input: String) -> String {
+    if self.expanded_inputs > 0 {
+      self.expanded_inputs -= 1;
+      return input;
+    }
+    let Some(expansions) = self.state.expand_alias(&input) else {
+      return input;
+    };
+    let mut inputs: Vec<String> = expansions
+      .iter()
+      .flat_map(|expansion| self.input.split(expansion))
+      .collect();
+    if inputs.is_empty() {
+      return input;
+    }
+    let first = inputs.remove(0);
+    self.expanded_inputs = inputs.len();
+    for input in inputs.into_iter().rev() {
+      self.state.input_queue.push_front(input);
+    }
+    first
+  }
+
+  /// Parse a line of input and queue the commands it produces, or tell the
+  /// player why it couldn't be parsed.
+  fn handle_input(&mut self, input: String) -> Result<(), GameError> {
+    let input = match self.parser.apply_oops(&input, &self.state) {
+      Some(Ok(corrected)) => corrected,
+      Some(Err(error)) => return self.handle_parser_error(&error),
+      None => input,
+    };
+    let answer = match self.state.pending_question.take() {
+      Some(question) => self
+        .parser
+        .parse_answer(&question, &input, &self.state)
+        .map(|result| (question.input, result)),
+      None => None,
+    };
+    let (mut input, mut result) = answer.unwrap_or_else(|| {
+      let result = self.parser.parse_commands(&input, &self.state);
+      (input, result)
+    });
+    // Correct one unknown word at a time, since the parser stops at the
+    // first one.
+    let mut corrections = input.split_whitespace().count();
+    while let Some((word, suggestion)) = unknown_word(&result) {
+      match suggestion.filter(|_| self.parser.autocorrects() && corrections > 0) {
+        Some(suggestion) => {
+          self
+            .output
+            .writeln(&format!("[Assuming you meant '{}'.]", suggestion))?;
+          input = spelling::replace_word(&input, &word, &suggestion);
+          result = self.parser.parse_commands(&input, &self.state);
+          corrections -= 1;
+        },
+        None => {
+          self.state.failed_input = Some(FailedInput {
+            input: input.clone(),
+            word,
+          });
+          break;
+        },
+      }
+    }
+    match result {
+      Ok(commands) => {
+        self.state.failed_input = None;
+        let objects: Vec<ObjectId> = commands.iter().flat_map(Command::objects).collect();
+        if !objects.is_empty() {
+          self.state.last_objects = objects;
+        }
+        self.input_snapshot = Some(self.state.snapshot());
+        for command in commands {
+          self.state.enqueue_command(command);
+        }
+        self.current_input = input;
+      },
+      Err(ParserError::AmbiguousNounPhrase {
+        input,
+        noun_phrase,
+        candidates,
+        ..
+      }) => {
+        let question = PendingQuestion {
+          input,
+          noun_phrase,
+          candidates,
+        };
+        self.output.writeln(&question.question(&self.state.world))?;
+        self.state.pending_question = Some(question);
+        self.state.clear_input_and_command_queues();
+      },
+      Err(error) => self.handle_parser_error(&error)?,
+    }
     Ok(())
   }
 
   /// Update game state, NPC behaviors, environment changes, etc.
+  ///
+  /// This runs after each command that takes time, advancing the clock and
+  /// firing any fuses and daemons that are due.
   fn update(&mut self) -> Result<(), GameError> {
+    if let Err(CommandError::Death(message)) = self.update_world() {
+      self.end_game(&message)?;
+    }
+    Ok(())
+  }
+
+  /// Let the world move on by a turn, failing with
+  /// [`CommandError::Death`] if the player dies; any other errors are shown
+  /// to the player.
+  fn update_world(&mut self) -> Result<(), CommandError> {
+    self.state.world.clock.tick();
+    for name in self.state.scheduler.tick() {
+      let result = match self.events.get(&name) {
+        Some(command) => command.execute(&mut self.state),
+        None => Err(CommandError::Unexpected(format!("No event named '{}'.", name))),
+      };
+      match result {
+        Err(error @ CommandError::Death(_)) => return Err(error),
+        Err(error) => self.state.enqueue_output(error.to_string()),
+        Ok(()) => {},
+      }
+    }
+    self.state.update_combat()?;
+    self.state.update_npcs();
     Ok(())
   }
 
+  /// End the game because the player has died, then ask them whether to
+  /// restart, restore a saved game, undo their last move, or quit, until
+  /// they give an answer that works.
+  fn end_game(&mut self, message: &str) -> Result<(), GameError> {
+    self.state.clear_input_and_command_queues();
+    self.process_output()?;
+    self.output.writeln(message)?;
+    let death = self
+      .state
+      .world
+      .message("death")
+      .unwrap_or(DEFAULT_DEATH_MESSAGE)
+      .to_string();
+    self.output.writeln(&death)?;
+    loop {
+      self.output.writeln(END_OF_GAME_QUESTION)?;
+      self.output.prompt()?;
+      let Some(inputs) = self.input.read_inputs()? else {
+        // EOF
+        self.state.set_quit_flag(true);
+        return Ok(());
+      };
+      let answer = inputs.first().map(|input| input.trim()).unwrap_or_default();
+      let (word, rest) = answer.split_once(char::is_whitespace).unwrap_or((answer, ""));
+      let result = match word.to_lowercase().as_str() {
+        "restart" => {
+          self.restart();
+          return Ok(());
+        },
+        "restore" if !rest.trim().is_empty() => RestoreCommand::new(rest.trim()).execute(&mut self.state),
+        "undo" => UndoCommand::new(1).execute(&mut self.state),
+        "quit" | "q" => {
+          self.state.set_quit_flag(true);
+          return Ok(());
+        },
+        _ => continue,
+      };
+      match result {
+        Ok(()) => return Ok(()),
+        Err(error) => self.handle_command_error(&error)?,
+      }
+    }
+  }
+
+  /// Start the game again from the beginning, keeping the player's aliases.
+  fn restart(&mut self) {
+    let initial = self.initial_state.clone().unwrap_or_default();
+    let aliases = mem::take(&mut self.state.aliases);
+    self.state.restore(initial);
+    self.state.aliases = aliases;
+    self.state.undo_history.clear();
+    self.state.last_command = None;
+    if let Some(description) = self.state.describe_current_room() {
+      self.state.enqueue_output(description);
+    }
+  }
+
   /// Send updates to players or render the game state in some form.
   fn process_output(&mut self) -> Result<(), GameError> {
-    self
-      .output
-      .writeln("You are standing in an open field west of a white house, with a boarded front door.")?;
+    while let Some(output) = self.state.dequeue_output() {
+      self.output.writeln(&output)?;
+    }
     Ok(())
   }
 
@@ -119,26 +474,37 @@ where
     Ok(())
   }
 
-  /// Handle invalid input.
-  fn handle_invalid_input(&mut self, input: &str) -> Result<(), GameError> {
-    self
-      .output
-      .writeln(&format!("I'm sorry, I don't understand '{}'.", input))?;
+  /// Tell the player why a command failed. In-character failures are part of
+  /// the story; anything else is marked as an error.
+  fn handle_command_error(&mut self, error: &CommandError) -> Result<(), GameError> {
+    match error {
+      CommandError::InCharacter(message) => self.output.writeln(message)?,
+      error => self.output.writeln(&format!("Error: {}", error))?,
+    }
+    Ok(())
+  }
+
+  /// Handle input that could not be parsed, by telling the player why.
+  fn handle_parser_error(&mut self, error: &ParserError) -> Result<(), GameError> {
+    self.output.writeln(&error.to_string())?;
     self.state.clear_input_queue();
     self.state.clear_command_queue();
     Ok(())
   }
 }
 
+/// The unknown word that made parsing fail, if any, with its suggested
+/// correction.
+fn unknown_word<T>(result: &Result<T, ParserError>) -> Option<(String, Option<String>)> {
+  let error = result.as_ref().err()?;
+  let word = error.unknown_word()?.to_string();
+  Some((word, error.suggestion().map(str::to_string)))
+}
+
 impl GameLoop<StdinLock<'static>, Stdout> {
   /// Create a new game loop with standard input and output.
   pub fn new_with_stdio() -> Self {
-    GameLoop {
-      state: GameState::default(),
-      input: StdinReader::default(),
-      output: StdoutWriter::default(),
-      parser: Parser,
-    }
+    GameLoop::new(StdinReader::default(), StdoutWriter::default())
   }
 }
 
@@ -147,12 +513,10 @@ impl GameLoop<BufReader<File>, BufWriter<File>> {
   pub fn new_with_files(input: &str, output: &str) -> Self {
     let input_file = File::open(input).unwrap();
     let output_file = File::create(output).unwrap();
-    GameLoop {
-      state: GameState::default(),
-      input: InputReader::new(BufReader::new(input_file)),
-      output: OutputWriter::new(BufWriter::new(output_file)),
-      parser: Parser,
-    }
+    GameLoop::new(
+      InputReader::new(BufReader::new(input_file)),
+      OutputWriter::new(BufWriter::new(output_file)),
+    )
   }
 }
 
@@ -165,13 +529,46 @@ impl Default for GameLoop<StdinLock<'static>, Stdout> {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::command::prelude::CommandError;
-  use crate::command::prelude::{Command, QuitCommand};
-  use crate::input::prelude::MockReader;
-  use crate::output::prelude::MockWriter;
+  use crate::command::prelude::{Command, CustomCommand, QuitCommand, ThrowErrorCommand};
+  use crate::command::registry::tests::Dig;
+  use crate::game::prelude::Combatant;
+  use crate::input::prelude::{InputMock, MockReader};
+  use crate::output::prelude::{MockWriter, OutputMock};
+  use crate::world::prelude::{Clock, Location, Object, Room, WorldError};
   use pretty_assertions::assert_eq;
+  use std::io::Write;
+  use std::sync::Arc;
   use tempfile::NamedTempFile;
 
+  /// A bomb, which explodes when its fuse runs out.
+  #[derive(Debug)]
+  struct Explode;
+
+  impl CustomCommand for Explode {
+    fn execute(&self, game_state: &mut GameState) -> Result<(), CommandError> {
+      game_state.scheduler.stop("tick");
+      game_state.enqueue_output("The bomb explodes.".to_string());
+      Ok(())
+    }
+  }
+
+  fn test_world() -> World {
+    World::new()
+      .with_room(
+        "west_of_house",
+        Room::new(
+          "West of House",
+          "You are standing in an open field west of a white house, with a boarded front door.",
+        )
+        .with_exit("north", "north_of_house"),
+      )
+      .with_room(
+        "north_of_house",
+        Room::new("North of House", "You are facing the north side of a white house."),
+      )
+      .with_player_location("west_of_house")
+  }
+
   #[test]
   fn test_run() -> Result<(), CommandError> {
     let mut game_loop = GameLoop::new_with_stdio();
@@ -186,20 +583,15 @@ mod tests {
     let mut mock_reader = MockReader::default();
     mock_reader.add_line("invalid".to_string());
     mock_reader.add_line("quit".to_string());
-    let mut game_loop = GameLoop {
-      state: GameState::default(),
-      input: mock_reader,
-      output: MockWriter::default(),
-      parser: Parser,
-    };
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
     assert!(game_loop.run().is_ok());
     assert_eq!(
       game_loop.output.output(),
       vec![
-        "You are standing in an open field west of a white house, with a boarded front door.",
+        "West of House\nYou are standing in an open field west of a white house, with a boarded front door.",
         "\n",
         "> ",
-        "I'm sorry, I don't understand 'invalid'.",
+        "I don't know the verb 'invalid'.",
         "\n",
         "> ",
       ]
@@ -211,17 +603,12 @@ mod tests {
   fn test_run_quit() -> Result<(), CommandError> {
     let mut mock_reader = MockReader::default();
     mock_reader.add_line("quit".to_string());
-    let mut game_loop = GameLoop {
-      state: GameState::default(),
-      input: mock_reader,
-      output: MockWriter::default(),
-      parser: Parser,
-    };
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
     assert!(game_loop.run().is_ok());
     assert_eq!(
       game_loop.output.output(),
       vec![
-        "You are standing in an open field west of a white house, with a boarded front door.",
+        "West of House\nYou are standing in an open field west of a white house, with a boarded front door.",
         "\n",
         "> ",
       ]
@@ -233,21 +620,14 @@ mod tests {
   fn test_run_succeed() -> Result<(), CommandError> {
     let mut mock_reader = MockReader::default();
     mock_reader.add_line("debug:succeed".to_string());
-    let mut game_loop = GameLoop {
-      state: GameState::default(),
-      input: mock_reader,
-      output: MockWriter::default(),
-      parser: Parser,
-    };
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
     assert!(game_loop.run().is_ok());
     assert_eq!(
       game_loop.output.output(),
       vec![
-        "You are standing in an open field west of a white house, with a boarded front door.",
+        "West of House\nYou are standing in an open field west of a white house, with a boarded front door.",
         "\n",
         "> ",
-        "You are standing in an open field west of a white house, with a boarded front door.",
-        "\n",
         "> ",
       ]
     );
@@ -258,20 +638,15 @@ mod tests {
   fn test_run_throw_ic_error() -> Result<(), CommandError> {
     let mut mock_reader = MockReader::default();
     mock_reader.add_line("debug:throw_error:ic".to_string());
-    let mut game_loop = GameLoop {
-      state: GameState::default(),
-      input: mock_reader,
-      output: MockWriter::default(),
-      parser: Parser,
-    };
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
     assert!(game_loop.run().is_ok());
     assert_eq!(
       game_loop.output.output(),
       vec![
-        "You are standing in an open field west of a white house, with a boarded front door.",
+        "West of House\nYou are standing in an open field west of a white house, with a boarded front door.",
         "\n",
         "> ",
-        "Error: Test error.",
+        "Test error.",
         "\n",
         "> ",
       ]
@@ -283,20 +658,341 @@ mod tests {
   fn test_run_throw_oc_error() -> Result<(), CommandError> {
     let mut mock_reader = MockReader::default();
     mock_reader.add_line("debug:throw_error:oc".to_string());
-    let mut game_loop = GameLoop {
-      state: GameState::default(),
-      input: mock_reader,
-      output: MockWriter::default(),
-      parser: Parser,
-    };
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop.output.output().contains(&"Error: Test error.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_go() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("west".to_string());
+    mock_reader.add_line("n".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
+    assert!(game_loop.run().is_ok());
+    assert_eq!(game_loop.state.world.player_location, "north_of_house");
+    assert_eq!(
+      game_loop.output.output(),
+      vec![
+        "West of House\nYou are standing in an open field west of a white house, with a boarded front door.",
+        "\n",
+        "> ",
+        "You can't go that way.",
+        "\n",
+        "> ",
+        "North of House\nYou are facing the north side of a white house.",
+        "\n",
+        "> ",
+      ]
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_take_and_inventory() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("take leaflet".to_string());
+    mock_reader.add_line("inventory".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
+    game_loop.state.world.add_object(
+      "leaflet",
+      Object::new("leaflet", "WELCOME TO SORK!").at(Location::Room("west_of_house".to_string())),
+    );
+    assert!(game_loop.run().is_ok());
+    assert_eq!(
+      game_loop.output.output(),
+      vec![
+        "West of House\nYou are standing in an open field west of a white house, with a boarded front door.\nThere \
+         is a leaflet here.",
+        "\n",
+        "> ",
+        "Taken.",
+        "\n",
+        "> ",
+        "You are carrying:\n  a leaflet",
+        "\n",
+        "> ",
+      ]
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_save_and_restore() -> Result<(), CommandError> {
+    let directory = tempfile::TempDir::new().unwrap();
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("save game".to_string());
+    mock_reader.add_line("n".to_string());
+    mock_reader.add_line("restore game".to_string());
+    mock_reader.add_line("restore missing".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_save_directory(directory.path());
+    assert!(game_loop.run().is_ok());
+    assert_eq!(game_loop.state.world.player_location, "west_of_house");
+    let output = game_loop.output.output();
+    assert!(output.contains(&"Saved as 'game'.".to_string()));
+    assert!(output.contains(&"Restored 'game'.".to_string()));
+    assert!(output.contains(&"Error: There is no saved game by that name.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_undo() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("n".to_string());
+    mock_reader.add_line("w".to_string());
+    mock_reader.add_line("undo".to_string());
+    mock_reader.add_line("undo".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
     assert!(game_loop.run().is_ok());
+    assert_eq!(game_loop.state.world.player_location, "west_of_house");
+    let output = game_loop.output.output();
+    assert!(output.contains(&"[Undone: n]".to_string()));
+    assert!(output.contains(&"Error: There is nothing to undo.".to_string()));
     Ok(())
   }
 
+  #[test]
+  fn test_run_registered_command() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("dig with shovel".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_command(Dig);
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop
+      .output
+      .output()
+      .contains(&"You dig a hole with the shovel.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_say_and_phrase() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("say \"Ahoy; matey. Then, farewell\"; yell \"Hello, Sailor!\"".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_phrase("hello sailor", Command::Quit(QuitCommand));
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop
+      .output
+      .output()
+      .contains(&"You say, \"Ahoy; matey. Then, farewell\"".to_string()));
+    assert!(game_loop.state.quit_flag());
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_aliases_and_macros() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("alias tl = take; macro dig = \"take lantern; dig. drop it\"".to_string());
+    mock_reader.add_line("tl lantern; drop lantern".to_string());
+    mock_reader.add_line("dig".to_string());
+    mock_reader.add_line("unalias tl; tl lantern".to_string());
+    let world = test_world().with_object(
+      "lantern",
+      Object::new("brass lantern", "").at(Location::Room("west_of_house".to_string())),
+    );
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(world)
+      .with_command(Dig);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    assert!(output.contains(&"'tl' now means 'take'.".to_string()));
+    assert!(output.contains(&"'dig' now means 'take lantern; dig. drop it'.".to_string()));
+    assert_eq!(output.iter().filter(|line| *line == "Taken.").count(), 2);
+    // The macro's "dig" is the verb, not the macro again.
+    assert!(output.contains(&"You dig a hole with your bare hands.".to_string()));
+    assert!(output.contains(&"'tl' no longer means anything.".to_string()));
+    assert!(output.contains(&"I don't know the verb 'tl'.".to_string()));
+    assert_eq!(
+      game_loop.state.aliases.get("dig"),
+      Some(&vec!["take lantern; dig. drop it".to_string()])
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_alias_ends_at_separator() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    // An alias stands for a single input, so the rest of the line is run.
+    mock_reader.add_line("alias x = look; n".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop.output.output().contains(&"'x' now means 'look'.".to_string()));
+    assert_eq!(game_loop.state.world.player_location, "north_of_house");
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_disambiguation() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("drop key".to_string());
+    mock_reader.add_line("the iron one".to_string());
+    mock_reader.add_line("drop key".to_string());
+    mock_reader.add_line("look".to_string());
+    let world = test_world()
+      .with_object("brass_key", Object::new("brass key", "").at(Location::Player))
+      .with_object("iron_key", Object::new("iron key", "").at(Location::Player));
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(world);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    assert!(output.contains(&"Which do you mean, the brass key or the iron key?".to_string()));
+    // The iron key is now in the room, so it comes first.
+    assert!(output.contains(&"Which do you mean, the iron key or the brass key?".to_string()));
+    assert!(output.contains(&"Dropped.".to_string()));
+    assert_eq!(
+      game_loop.state.world.object("iron_key").unwrap().location,
+      Location::Room("west_of_house".to_string())
+    );
+    assert_eq!(
+      game_loop.state.world.object("brass_key").unwrap().location,
+      Location::Player
+    );
+    assert!(game_loop.state.pending_question.is_none());
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_pronouns_and_again() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("g".to_string());
+    mock_reader.add_line("take leaflet".to_string());
+    mock_reader.add_line("put it in the mailbox".to_string());
+    mock_reader.add_line("look".to_string());
+    mock_reader.add_line("again".to_string());
+    let world = test_world()
+      .with_object(
+        "mailbox",
+        Object::new("small mailbox", "")
+          .at(Location::Room("west_of_house".to_string()))
+          .container(),
+      )
+      .with_object(
+        "leaflet",
+        Object::new("leaflet", "").at(Location::Room("west_of_house".to_string())),
+      );
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(world);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    assert!(output.contains(&"Error: There is nothing to repeat.".to_string()));
+    assert_eq!(
+      game_loop.state.world.object("leaflet").unwrap().location,
+      Location::In("mailbox".to_string())
+    );
+    let descriptions = output.iter().filter(|line| line.starts_with("West of House")).count();
+    assert_eq!(descriptions, 3);
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_take_all() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("take all".to_string());
+    mock_reader.add_line("undo".to_string());
+    let world = test_world()
+      .with_object(
+        "rope",
+        Object::new("rope", "").at(Location::Room("west_of_house".to_string())),
+      )
+      .with_object(
+        "anvil",
+        Object::new("iron anvil", "")
+          .at(Location::Room("west_of_house".to_string()))
+          .fixed(),
+      )
+      .with_object(
+        "leaflet",
+        Object::new("leaflet", "").at(Location::Room("west_of_house".to_string())),
+      );
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(world);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    assert!(output.contains(&"leaflet: Taken.".to_string()));
+    assert!(output.contains(&"rope: Taken.".to_string()));
+    assert!(!output.iter().any(|line| line.starts_with("iron anvil")));
+    assert!(output.contains(&"[Undone: take all]".to_string()));
+    assert_eq!(game_loop.state.world.inventory(), Vec::<ObjectId>::new());
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_spelling_suggestion_and_oops() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("oops lantern".to_string());
+    mock_reader.add_line("tkae the leaflte".to_string());
+    mock_reader.add_line("take the leaflte".to_string());
+    mock_reader.add_line("oops leaflet".to_string());
+    let world = test_world().with_object(
+      "leaflet",
+      Object::new("leaflet", "").at(Location::Room("west_of_house".to_string())),
+    );
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(world);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    assert!(output.contains(&"There was no word to replace.".to_string()));
+    assert!(output.contains(&"I don't know the verb 'tkae'. Did you mean 'take'?".to_string()));
+    assert!(output.contains(&"I don't know the word 'leaflte'. Did you mean 'leaflet'?".to_string()));
+    assert!(output.contains(&"Taken.".to_string()));
+    assert!(game_loop.state.failed_input.is_none());
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_autocorrect() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("tkae the leaflte".to_string());
+    let world = test_world().with_object(
+      "leaflet",
+      Object::new("leaflet", "").at(Location::Room("west_of_house".to_string())),
+    );
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(world)
+      .with_autocorrect(true);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    // The verb is corrected first, and then the noun.
+    assert!(output.contains(&"[Assuming you meant 'take'.]".to_string()));
+    assert!(output.contains(&"[Assuming you meant 'leaflet'.]".to_string()));
+    assert!(output.contains(&"Taken.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_undo_limit() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("n".to_string());
+    mock_reader.add_line("wait".to_string());
+    mock_reader.add_line("look".to_string());
+    mock_reader.add_line("wait".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_undo_limit(2);
+    assert!(game_loop.run().is_ok());
+    assert_eq!(game_loop.state.undo_history.len(), 2);
+    assert_eq!(game_loop.state.undo_history[0].label, "wait");
+    assert_eq!(game_loop.state.undo_history[1].label, "wait");
+    Ok(())
+  }
+
+  #[test]
+  fn test_undo_skips_meta_commands() {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("n; look; inventory; time".to_string());
+    mock_reader.add_line("undo".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop.output.output().contains(&"[Undone: n]".to_string()));
+    assert_eq!(game_loop.state.world.player_location, "west_of_house");
+  }
+
   #[test]
   fn test_is_finished() -> Result<(), CommandError> {
     let mut game_loop = GameLoop::new_with_stdio();
-    assert_eq!(game_loop.is_finished(), false);
+    assert!(!game_loop.is_finished());
     let quit_command = Command::Quit(QuitCommand);
     quit_command.execute(&mut game_loop.state)?;
     assert!(game_loop.is_finished());
@@ -314,27 +1010,273 @@ mod tests {
     let mut mock_reader = MockReader::default();
     mock_reader.add_line("test".to_string());
     mock_reader.add_line("quit".to_string());
-    let mut game_loop = GameLoop {
-      state: GameState::default(),
-      input: mock_reader,
-      output: MockWriter::default(),
-      parser: Parser,
-    };
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
     assert!(game_loop.process_input().is_ok());
   }
 
+  #[test]
+  fn test_process_input_takes_time() -> Result<(), GameError> {
+    let mut mock_reader = MockReader::default();
+    for line in [
+      "look",
+      "save",
+      "inventory",
+      "debug:succeed",
+      "wait",
+      "n",
+      "alias z = wait",
+    ] {
+      mock_reader.add_line(line.to_string());
+    }
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(test_world());
+    let mut took_time = Vec::new();
+    while !game_loop.is_finished() {
+      took_time.push(game_loop.process_input()?);
+    }
+    // "save" fails without a name, so "inventory" is the next to succeed.
+    assert_eq!(took_time, vec![false, false, false, true, true, false, false]);
+    Ok(())
+  }
+
+  #[test]
+  fn test_setup_world_file() {
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(
+      file,
+      "player_location = \"cellar\"\n[messages]\nintro = \"Welcome!\"\n[rooms.cellar]\nname = \"Cellar\"\n\
+       description = \"You are in a dark and damp cellar.\""
+    )
+    .unwrap();
+    file.flush().unwrap();
+    let mut game_loop = GameLoop::new(MockReader::default(), MockWriter::default()).with_world_file(file.path());
+    assert!(game_loop.setup().is_ok());
+    assert_eq!(game_loop.state.world.player_location, "cellar");
+    assert_eq!(game_loop.state.dequeue_output(), Some("Welcome!".to_string()));
+    assert_eq!(
+      game_loop.state.dequeue_output(),
+      Some("Cellar\nYou are in a dark and damp cellar.".to_string())
+    );
+  }
+
+  #[test]
+  fn test_setup_world_file_error() {
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(file, "[rooms.cellar]\nname = Cellar").unwrap();
+    file.flush().unwrap();
+    let mut game_loop = GameLoop::new(MockReader::default(), MockWriter::default()).with_world_file(file.path());
+    let result = game_loop.setup();
+    assert!(matches!(
+      result,
+      Err(GameError::WorldError(WorldError::SyntaxError {
+        line: 2,
+        column: 8,
+        ..
+      }))
+    ));
+  }
+
   #[test]
   fn test_update() {
     let mut game_loop = GameLoop::new_with_stdio();
     assert!(game_loop.update().is_ok());
   }
 
+  #[test]
+  fn test_run_clock() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("wait; look; n; inventory; time".to_string());
+    let world = test_world().with_clock(Clock::new(8, 0).with_minutes_per_turn(10));
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default()).with_world(world);
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop
+      .output
+      .output()
+      .contains(&"It is 8:20 am on day 1. You have taken 2 turns.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_fuses_and_daemons() -> Result<(), CommandError> {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("wait; look; wait; wait".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_event("bomb", Command::Custom(Arc::new(Explode)))
+      .with_event(
+        "tick",
+        Command::ThrowError(ThrowErrorCommand {
+          error: CommandError::InCharacter("Tick.".to_string()),
+        }),
+      );
+    game_loop.state.scheduler.start_daemon("tick");
+    game_loop.state.scheduler.start_fuse("bomb", 2);
+    game_loop.state.scheduler.start_daemon("missing");
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    let events: Vec<&String> = output
+      .iter()
+      .filter(|line| matches!(line.as_str(), "Tick." | "The bomb explodes."))
+      .collect();
+    assert_eq!(events, vec!["Tick.", "Tick.", "The bomb explodes."]);
+    assert!(output.contains(&"An unexpected error occurred: No event named 'missing'.".to_string()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_npcs() {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("north; look".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_npc(
+        "goblin",
+        Npc::new("goblin", "A goblin is here.").at("west_of_house").follow(),
+      );
+    assert!(game_loop.run().is_ok());
+    assert_eq!(game_loop.state.npcs["goblin"].location, "north_of_house");
+    let output = game_loop.output.output();
+    assert!(output.contains(&"A goblin enters from the south.".to_string()));
+    let look = output
+      .iter()
+      .rev()
+      .find(|line| line.starts_with("North of House"))
+      .unwrap();
+    assert!(look.ends_with("white house.\nA goblin is here."));
+  }
+
+  #[test]
+  fn test_run_npc_schedule() {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("look; wait; wait".to_string());
+    let cook = Npc::new("cook", "")
+      .at("west_of_house")
+      .with_appointment(9, "north_of_house");
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_npc("cook", cook);
+    assert!(game_loop.run().is_ok());
+    assert_eq!(game_loop.state.npcs["cook"].location, "north_of_house");
+    let output = game_loop.output.output();
+    assert_eq!(
+      output
+        .iter()
+        .filter(|line| *line == "A cook leaves to the north.")
+        .count(),
+      1
+    );
+  }
+
+  /// A game loop whose world has a goblin that kills with one blow once
+  /// provoked.
+  fn deadly_game_loop(lines: &[&str]) -> GameLoop<InputMock, OutputMock> {
+    let mut mock_reader = MockReader::default();
+    for line in lines {
+      mock_reader.add_line(line.to_string());
+    }
+    let goblin = Npc::new("goblin", "A goblin is here.")
+      .at("west_of_house")
+      .with_combatant(Combatant::new(5, 10, 0));
+    GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_npc("goblin", goblin)
+  }
+
+  #[test]
+  fn test_run_death_and_undo() {
+    let mut game_loop = deadly_game_loop(&["attack goblin", "dance", "undo", "look"]);
+    assert!(game_loop.run().is_ok());
+    let output = game_loop.output.output();
+    let death = output.iter().position(|line| line == DEFAULT_DEATH_MESSAGE).unwrap();
+    assert_eq!(output[death - 2], "The goblin strikes a fatal blow.");
+    let questions = output.iter().filter(|line| *line == END_OF_GAME_QUESTION).count();
+    assert_eq!(questions, 2);
+    assert!(output.contains(&"[Undone: attack goblin]".to_string()));
+    assert_eq!(game_loop.state.player.hit_points, game_loop.state.player.max_hit_points);
+    assert!(!game_loop.state.npcs["goblin"].hostile);
+  }
+
+  #[test]
+  fn test_run_death_and_restart() {
+    let mut game_loop = deadly_game_loop(&["north", "south", "attack goblin", "RESTART", "undo"]);
+    game_loop.state.world.add_room(
+      "north_of_house",
+      Room::new("North of House", "").with_exit("south", "west_of_house"),
+    );
+    assert!(game_loop.run().is_ok());
+    assert!(!game_loop.state.npcs["goblin"].hostile);
+    assert!(!game_loop.state.player.is_dead());
+    assert_eq!(game_loop.state.world.clock.turns, 0);
+    assert!(game_loop
+      .output
+      .output()
+      .contains(&"Error: There is nothing to undo.".to_string()));
+  }
+
+  #[test]
+  fn test_run_death_by_event() {
+    let mut mock_reader = MockReader::default();
+    mock_reader.add_line("wait".to_string());
+    mock_reader.add_line("restore".to_string());
+    mock_reader.add_line("quit".to_string());
+    let mut game_loop = GameLoop::new(mock_reader, MockWriter::default())
+      .with_world(test_world())
+      .with_event(
+        "grue",
+        Command::ThrowError(ThrowErrorCommand {
+          error: CommandError::Death("You are eaten by a grue.".to_string()),
+        }),
+      );
+    game_loop.state.scheduler.start_fuse("grue", 1);
+    game_loop
+      .state
+      .world
+      .messages
+      .insert("death".to_string(), "You are dead.".to_string());
+    assert!(game_loop.run().is_ok());
+    assert!(game_loop.state.quit_flag());
+    let output = game_loop.output.output();
+    let death = output
+      .iter()
+      .position(|line| line == "You are eaten by a grue.")
+      .unwrap();
+    assert_eq!(output[death + 2], "You are dead.");
+    let questions = output.iter().filter(|line| *line == END_OF_GAME_QUESTION).count();
+    assert_eq!(questions, 2);
+  }
+
   #[test]
   fn test_process_output() {
     let mut game_loop = GameLoop::new_with_stdio();
     assert!(game_loop.process_output().is_ok());
   }
 
+  #[test]
+  fn test_process_output_drains_output_queue() {
+    let mut game_loop = GameLoop::new(MockReader::default(), MockWriter::default()).with_world(test_world());
+    game_loop.state.enqueue_output("Taken.".to_string());
+    game_loop.state.enqueue_output("Dropped.".to_string());
+    assert!(game_loop.process_output().is_ok());
+    assert!(game_loop.state.output_queue.is_empty());
+    assert_eq!(game_loop.output.output(), vec!["Taken.", "\n", "Dropped.", "\n"]);
+  }
+
+  #[test]
+  fn test_setup_describes_current_room() {
+    let mut game_loop = GameLoop::new(MockReader::default(), MockWriter::default()).with_world(test_world());
+    game_loop.state.world.player_location = "north_of_house".to_string();
+    assert!(game_loop.setup().is_ok());
+    assert_eq!(
+      game_loop.state.dequeue_output(),
+      Some("North of House\nYou are facing the north side of a white house.".to_string())
+    );
+  }
+
+  #[test]
+  fn test_with_world() {
+    let game_loop = GameLoop::new_with_stdio().with_world(test_world());
+    assert_eq!(game_loop.state.world, test_world());
+  }
+
   #[test]
   fn test_teardown() {
     let mut game_loop = GameLoop::new_with_stdio();
@@ -345,6 +1287,6 @@ mod tests {
   fn test_default() {
     let temp_file = NamedTempFile::new().unwrap();
     let game_loop = GameLoop::new_with_files(temp_file.path().to_str().unwrap(), temp_file.path().to_str().unwrap());
-    assert_eq!(game_loop.state.quit_flag(), false);
+    assert!(!game_loop.state.quit_flag());
   }
 }
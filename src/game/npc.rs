@@ -0,0 +1,447 @@
+use crate::game::combat::Combatant;
+use crate::world::prelude::{Clock, Direction, Exit, RoomId, World};
+use serde::{Deserialize, Serialize};
+
+/// An identifier for a non-player character.
+pub type NpcId = String;
+
+/// What a non-player character does each turn.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Behavior {
+  /// Stay put, emoting every so often (e.g. "The goblin scratches itself.").
+  Idle {
+    /// The emotes, which are used in turn.
+    emotes: Vec<String>,
+    /// The number of turns between emotes.
+    #[serde(default = "every_turn")]
+    every: u64,
+  },
+  /// Wander from room to room.
+  Wander {
+    /// The number of turns between moves.
+    #[serde(default = "every_turn")]
+    every: u64,
+    /// The rooms the character keeps to, or any rooms if this is empty.
+    #[serde(default)]
+    within: Vec<RoomId>,
+  },
+  /// Follow the player from room to room, one step behind.
+  Follow,
+}
+
+/// A place a non-player character should be from a particular hour of the
+/// day (e.g. the cook is in the kitchen from 6:00 am).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Appointment {
+  /// The hour from which the character should be there, from 0 to 23.
+  pub hour: u64,
+  /// The room the character should be in.
+  pub room: RoomId,
+}
+
+/// What a non-player character does on a particular turn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NpcAction {
+  /// Nothing.
+  Stay,
+  /// Emote (e.g. "The goblin scratches itself.").
+  Emote(String),
+  /// Leave through an exit.
+  Move {
+    /// The name of the exit (e.g. "east").
+    exit: String,
+    /// The room the exit leads to.
+    destination: RoomId,
+  },
+}
+
+/// A non-player character, which acts on its own as turns pass.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Npc {
+  /// The name of the character (e.g. "goblin").
+  pub name: String,
+  /// What the player sees when the character is in the same room (e.g. "A
+  /// goblin is sharpening its knife here.").
+  pub description: String,
+  /// The room the character is in.
+  pub location: RoomId,
+  /// What the character does each turn.
+  pub behavior: Behavior,
+  /// Where the character should be at different times of day, in order of
+  /// hour. While it is elsewhere, it heads there rather than following its
+  /// behavior.
+  #[serde(default)]
+  pub schedule: Vec<Appointment>,
+  /// How the character fights, if it can be fought at all.
+  #[serde(default)]
+  pub combatant: Option<Combatant>,
+  /// Whether the character is fighting the player, in which case it pursues
+  /// them and strikes back each turn.
+  #[serde(default)]
+  pub hostile: bool,
+}
+
+impl Npc {
+  /// Create a new character, which idles nowhere in particular.
+  pub fn new(name: &str, description: &str) -> Self {
+    Self {
+      name: name.to_string(),
+      description: description.to_string(),
+      location: RoomId::new(),
+      behavior: Behavior::Idle {
+        emotes: Vec::new(),
+        every: every_turn(),
+      },
+      schedule: Vec::new(),
+      combatant: None,
+      hostile: false,
+    }
+  }
+
+  /// Put the character in a room.
+  pub fn at(mut self, room: &str) -> Self {
+    self.location = room.to_string();
+    self
+  }
+
+  /// Make the character stay put, using the emotes in turn every so many
+  /// turns.
+  pub fn idle(mut self, emotes: &[&str], every: u64) -> Self {
+    self.behavior = Behavior::Idle {
+      emotes: emotes.iter().map(|emote| emote.to_string()).collect(),
+      every: every.max(1),
+    };
+    self
+  }
+
+  /// Make the character wander every so many turns, keeping to the rooms if
+  /// any are specified.
+  pub fn wander(mut self, every: u64, within: &[&str]) -> Self {
+    self.behavior = Behavior::Wander {
+      every: every.max(1),
+      within: within.iter().map(|room| room.to_string()).collect(),
+    };
+    self
+  }
+
+  /// Make the character follow the player.
+  pub fn follow(mut self) -> Self {
+    self.behavior = Behavior::Follow;
+    self
+  }
+
+  /// Have the character be in the room from the hour onward, until its next
+  /// appointment.
+  pub fn with_appointment(mut self, hour: u64, room: &str) -> Self {
+    self.schedule.push(Appointment {
+      hour,
+      room: room.to_string(),
+    });
+    self.schedule.sort_by_key(|appointment| appointment.hour);
+    self
+  }
+
+  /// Let the character be fought.
+  pub fn with_combatant(mut self, combatant: Combatant) -> Self {
+    self.combatant = Some(combatant);
+    self
+  }
+
+  /// Make the character fight the player on sight.
+  pub fn hostile(mut self) -> Self {
+    self.hostile = true;
+    self
+  }
+
+  /// The room the character should be in at the time shown by the clock,
+  /// if it has a schedule.
+  ///
+  /// Before the first appointment of the day, the last one of the previous
+  /// day still holds.
+  pub fn scheduled_room(&self, clock: &Clock) -> Option<&RoomId> {
+    let hour = clock.hour();
+    self
+      .schedule
+      .iter()
+      .rfind(|appointment| appointment.hour <= hour)
+      .or(self.schedule.last())
+      .map(|appointment| &appointment.room)
+  }
+
+  /// Whether the player's words (e.g. "goblin" or "big goblin") refer to
+  /// the character, which they do if its name includes all of them.
+  pub fn is_called(&self, words: &[&str]) -> bool {
+    let name: Vec<&str> = self.name.split_whitespace().collect();
+    !words.is_empty() && words.iter().all(|word| name.contains(word))
+  }
+
+  /// The name of the character with an indefinite article, capitalized to
+  /// start a sentence (e.g. "A goblin").
+  pub fn indefinite_name(&self) -> String {
+    match self.name.chars().next() {
+      Some('a' | 'e' | 'i' | 'o' | 'u') => format!("An {}", self.name),
+      _ => format!("A {}", self.name),
+    }
+  }
+
+  /// Decide what the character does this turn.
+  pub fn act(&self, world: &World) -> NpcAction {
+    if self.hostile {
+      return self.follow_player(world);
+    }
+    if let Some(room) = self.scheduled_room(&world.clock).filter(|room| **room != self.location) {
+      let step = world.route(&self.location, room).and_then(|route| {
+        let exit = route.into_iter().next()?;
+        let destination = world.room(&self.location)?.exit(&exit)?.destination.clone();
+        Some(NpcAction::Move { exit, destination })
+      });
+      return step.unwrap_or(NpcAction::Stay);
+    }
+    let turns = world.clock.turns;
+    match &self.behavior {
+      Behavior::Idle { emotes, every } => {
+        if !turns.is_multiple_of(*every) || emotes.is_empty() {
+          return NpcAction::Stay;
+        }
+        NpcAction::Emote(emotes[(turns / every) as usize % emotes.len()].clone())
+      },
+      Behavior::Wander { every, within } => {
+        if !turns.is_multiple_of(*every) {
+          return NpcAction::Stay;
+        }
+        let exits: Vec<(&String, &Exit)> = self
+          .exits(world)
+          .filter(|(_, exit)| within.is_empty() || within.contains(&exit.destination))
+          .collect();
+        match exits.get((turns / every) as usize % exits.len().max(1)) {
+          Some((name, exit)) => NpcAction::Move {
+            exit: name.to_string(),
+            destination: exit.destination.clone(),
+          },
+          None => NpcAction::Stay,
+        }
+      },
+      Behavior::Follow => self.follow_player(world),
+    }
+  }
+
+  /// What the player sees when the character leaves their room through the
+  /// exit (e.g. "A goblin leaves to the east.").
+  pub fn departure(&self, exit: &str) -> String {
+    match Direction::from_word(exit) {
+      Some(Direction::Up) => format!("{} goes up.", self.indefinite_name()),
+      Some(Direction::Down) => format!("{} goes down.", self.indefinite_name()),
+      Some(Direction::In | Direction::Out) | None => format!("{} leaves.", self.indefinite_name()),
+      Some(direction) => format!("{} leaves to the {}.", self.indefinite_name(), direction),
+    }
+  }
+
+  /// What the player sees when the character enters their room from the
+  /// room it was in, by the specified exit (e.g. "A goblin enters from the
+  /// west.").
+  ///
+  /// The direction is that of the exit back to the room it was in, or else
+  /// the opposite of the exit it took.
+  pub fn arrival(&self, world: &World, origin: &str, exit: &str) -> String {
+    let from = world
+      .room(&self.location)
+      .and_then(|room| {
+        room
+          .exits
+          .iter()
+          .filter(|(_, exit)| exit.destination == origin)
+          .find_map(|(name, _)| Direction::from_word(name))
+      })
+      .or_else(|| Direction::from_word(exit).map(|direction| direction.opposite()));
+    match from {
+      Some(Direction::Up) => format!("{} enters from above.", self.indefinite_name()),
+      Some(Direction::Down) => format!("{} enters from below.", self.indefinite_name()),
+      Some(Direction::In | Direction::Out) | None => format!("{} enters.", self.indefinite_name()),
+      Some(direction) => format!("{} enters from the {}.", self.indefinite_name(), direction),
+    }
+  }
+
+  /// Follow the player if they are in a neighboring room.
+  fn follow_player(&self, world: &World) -> NpcAction {
+    match self
+      .exits(world)
+      .find(|(_, exit)| exit.destination == world.player_location)
+    {
+      Some((name, exit)) if self.location != world.player_location => NpcAction::Move {
+        exit: name.clone(),
+        destination: exit.destination.clone(),
+      },
+      _ => NpcAction::Stay,
+    }
+  }
+
+  /// The exits from the character's room that aren't blocked by a closed
+  /// door.
+  fn exits<'a>(&self, world: &'a World) -> impl Iterator<Item = (&'a String, &'a Exit)> {
+    world
+      .room(&self.location)
+      .into_iter()
+      .flat_map(|room| room.exits.iter())
+      .filter(|(_, exit)| world.is_passable(exit))
+  }
+}
+
+/// The default number of turns between things a character does.
+fn every_turn() -> u64 {
+  1
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use crate::world::prelude::{Object, Room};
+
+  /// A row of three rooms: west, middle and east.
+  fn test_world() -> World {
+    World::new()
+      .with_room("west", Room::new("West", "").with_exit("east", "middle"))
+      .with_room(
+        "middle",
+        Room::new("Middle", "")
+          .with_exit("west", "west")
+          .with_exit_through("east", "east", "door"),
+      )
+      .with_room(
+        "east",
+        Room::new("East", "").with_exit_through("west", "middle", "door"),
+      )
+      .with_object("door", Object::new("door", "").fixed().openable())
+      .with_player_location("west")
+  }
+
+  #[test]
+  fn test_act_idle() {
+    test_utils::init();
+    let mut world = test_world();
+    let npc = Npc::new("goblin", "")
+      .at("west")
+      .idle(&["The goblin yawns.", "The goblin sneezes."], 2);
+    assert_eq!(npc.act(&world), NpcAction::Emote("The goblin yawns.".to_string()));
+    world.clock.tick();
+    assert_eq!(npc.act(&world), NpcAction::Stay);
+    world.clock.tick();
+    assert_eq!(npc.act(&world), NpcAction::Emote("The goblin sneezes.".to_string()));
+    assert_eq!(Npc::new("goblin", "").act(&world), NpcAction::Stay);
+  }
+
+  #[test]
+  fn test_act_wander() {
+    test_utils::init();
+    let mut world = test_world();
+    let npc = Npc::new("goblin", "").at("middle").wander(1, &[]);
+    let moves = |world: &World| match npc.act(world) {
+      NpcAction::Move { destination, .. } => Some(destination),
+      _ => None,
+    };
+    assert_eq!(moves(&world), Some("east".to_string()));
+    world.clock.tick();
+    assert_eq!(moves(&world), Some("west".to_string()));
+    // Closed doors and the rooms it keeps to hold it back.
+    world.object_mut("door").unwrap().closed = true;
+    world.clock.tick();
+    assert_eq!(moves(&world), Some("west".to_string()));
+    let npc = Npc::new("goblin", "").at("middle").wander(1, &["middle", "east"]);
+    assert_eq!(npc.act(&world), NpcAction::Stay);
+  }
+
+  #[test]
+  fn test_act_follow() {
+    test_utils::init();
+    let mut world = test_world();
+    let mut npc = Npc::new("goblin", "").at("west").follow();
+    assert_eq!(npc.act(&world), NpcAction::Stay);
+    world.player_location = "middle".to_string();
+    assert_eq!(
+      npc.act(&world),
+      NpcAction::Move {
+        exit: "east".to_string(),
+        destination: "middle".to_string(),
+      }
+    );
+    npc.location = "middle".to_string();
+    world.player_location = "east".to_string();
+    world.object_mut("door").unwrap().closed = true;
+    assert_eq!(npc.act(&world), NpcAction::Stay);
+  }
+
+  #[test]
+  fn test_act_hostile() {
+    test_utils::init();
+    let mut world = test_world();
+    world.player_location = "middle".to_string();
+    let npc = Npc::new("goblin", "")
+      .at("west")
+      .idle(&["The goblin glares."], 1)
+      .with_appointment(0, "west")
+      .hostile();
+    assert_eq!(
+      npc.act(&world),
+      NpcAction::Move {
+        exit: "east".to_string(),
+        destination: "middle".to_string(),
+      }
+    );
+  }
+
+  #[test]
+  fn test_scheduled_room() {
+    test_utils::init();
+    let npc = Npc::new("cook", "")
+      .with_appointment(20, "shrine")
+      .with_appointment(6, "kitchen");
+    assert_eq!(npc.scheduled_room(&Clock::new(6, 0)), Some(&"kitchen".to_string()));
+    assert_eq!(npc.scheduled_room(&Clock::new(19, 59)), Some(&"kitchen".to_string()));
+    assert_eq!(npc.scheduled_room(&Clock::new(20, 0)), Some(&"shrine".to_string()));
+    assert_eq!(npc.scheduled_room(&Clock::new(3, 0)), Some(&"shrine".to_string()));
+    assert_eq!(Npc::new("goblin", "").scheduled_room(&Clock::new(3, 0)), None);
+  }
+
+  #[test]
+  fn test_act_schedule() {
+    test_utils::init();
+    let mut world = test_world();
+    let mut npc = Npc::new("cook", "")
+      .at("east")
+      .idle(&["The cook stirs the pot."], 1)
+      .with_appointment(6, "west")
+      .with_appointment(20, "east");
+    assert_eq!(
+      npc.act(&world),
+      NpcAction::Move {
+        exit: "west".to_string(),
+        destination: "middle".to_string(),
+      }
+    );
+    npc.location = "west".to_string();
+    assert_eq!(npc.act(&world), NpcAction::Emote("The cook stirs the pot.".to_string()));
+    // A closed door keeps it from its appointment.
+    world.clock = Clock::new(20, 0);
+    world.object_mut("door").unwrap().closed = true;
+    npc.location = "middle".to_string();
+    assert_eq!(npc.act(&world), NpcAction::Stay);
+  }
+
+  #[test]
+  fn test_departure_and_arrival() {
+    test_utils::init();
+    let world = test_world();
+    let npc = Npc::new("goblin", "").at("middle");
+    assert!(Npc::new("big goblin", "").is_called(&["big", "goblin"]));
+    assert!(!Npc::new("big goblin", "").is_called(&["small", "goblin"]));
+    assert_eq!(npc.departure("east"), "A goblin leaves to the east.");
+    assert_eq!(npc.departure("up"), "A goblin goes up.");
+    assert_eq!(npc.departure("window"), "A goblin leaves.");
+    assert_eq!(npc.arrival(&world, "west", "east"), "A goblin enters from the west.");
+    // Without an exit back, the direction is the opposite of the exit taken.
+    let orc = Npc::new("orc", "").at("west");
+    assert_eq!(orc.arrival(&world, "east", "west"), "An orc enters from the east.");
+    assert_eq!(orc.arrival(&world, "east", "window"), "An orc enters.");
+  }
+}
@@ -0,0 +1,147 @@
+use crate::command::prelude::CommandError;
+use crate::game::state::GameState;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// The version of the save file format.
+///
+/// This should be incremented whenever a change to the game state would
+/// prevent older save files from being restored correctly.
+pub const SAVE_FILE_VERSION: u32 = 5;
+
+/// A saved game, as written to disk.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SaveFile {
+  /// The version of the save file format.
+  pub version: u32,
+  /// The saved game state.
+  pub state: GameState,
+}
+
+impl SaveFile {
+  /// Create a new save file from a snapshot of the game state, which leaves
+  /// out the undo history.
+  pub fn new(state: &mut GameState) -> Self {
+    Self {
+      version: SAVE_FILE_VERSION,
+      state: state.snapshot(),
+    }
+  }
+
+  /// Write the save file to disk.
+  pub fn write(&self, path: &Path) -> Result<(), CommandError> {
+    let contents = toml::to_string(self)
+      .map_err(|error| CommandError::Unexpected(format!("Could not serialize the game state: {}", error)))?;
+    fs::write(path, contents)
+      .map_err(|error| CommandError::OutOfCharacter(format!("Could not save the game: {}.", error)))?;
+    Ok(())
+  }
+
+  /// Read a save file from disk.
+  pub fn read(path: &Path) -> Result<Self, CommandError> {
+    let contents = fs::read_to_string(path).map_err(|error| match error.kind() {
+      ErrorKind::NotFound => CommandError::OutOfCharacter("There is no saved game by that name.".to_string()),
+      _ => CommandError::OutOfCharacter(format!("Could not restore the game: {}.", error)),
+    })?;
+    let save_file: SaveFile = toml::from_str(&contents)
+      .map_err(|_| CommandError::OutOfCharacter("That saved game is damaged and cannot be restored.".to_string()))?;
+    if save_file.version != SAVE_FILE_VERSION {
+      return Err(CommandError::OutOfCharacter(format!(
+        "That saved game is from an incompatible version (version {}, expected {}).",
+        save_file.version, SAVE_FILE_VERSION
+      )));
+    }
+    Ok(save_file)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::game::npc::Npc;
+  use crate::game::state::UndoSnapshot;
+  use crate::test_utils;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_write_and_read() -> Result<(), CommandError> {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let path = directory.path().join("test.sav");
+    let mut game_state = test_utils::test_state();
+    game_state.enqueue_input("look".to_string());
+    game_state
+      .aliases
+      .insert("dig".to_string(), vec!["take shovel".to_string(), "dig".to_string()]);
+    game_state.scheduler.start_daemon("battery");
+    game_state.scheduler.start_fuse("bomb", 5);
+    game_state.npcs.insert(
+      "goblin".to_string(),
+      Npc::new("goblin", "").at("west_of_house").wander(2, &[]),
+    );
+    let snapshot = UndoSnapshot {
+      label: "look".to_string(),
+      state: game_state.snapshot(),
+    };
+    game_state.push_undo_snapshot(snapshot, 5);
+    let save_file = SaveFile::new(&mut game_state);
+    assert!(save_file.state.undo_history.is_empty());
+    assert_eq!(game_state.undo_history.len(), 1);
+    save_file.write(&path)?;
+    let save_file = SaveFile::read(&path)?;
+    assert_eq!(save_file.version, SAVE_FILE_VERSION);
+    assert_eq!(save_file.state.scheduler, game_state.scheduler);
+    assert_eq!(save_file.state.npcs, game_state.npcs);
+    assert_eq!(save_file.state.world, game_state.world);
+    assert_eq!(save_file.state.aliases, game_state.aliases);
+    assert!(save_file.state.input_queue.is_empty());
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_missing() {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let result = SaveFile::read(&directory.path().join("missing.sav"));
+    assert_eq!(
+      result.unwrap_err(),
+      CommandError::OutOfCharacter("There is no saved game by that name.".to_string())
+    );
+  }
+
+  #[test]
+  fn test_read_damaged() {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let path = directory.path().join("damaged.sav");
+    fs::write(&path, "this is not a saved game").unwrap();
+    let result = SaveFile::read(&path);
+    assert_eq!(
+      result.unwrap_err(),
+      CommandError::OutOfCharacter("That saved game is damaged and cannot be restored.".to_string())
+    );
+  }
+
+  #[test]
+  fn test_read_wrong_version() -> Result<(), CommandError> {
+    test_utils::init();
+    let directory = TempDir::new().unwrap();
+    let path = directory.path().join("old.sav");
+    let mut save_file = SaveFile::new(&mut test_utils::test_state());
+    for version in [SAVE_FILE_VERSION - 1, SAVE_FILE_VERSION + 1] {
+      save_file.version = version;
+      save_file.write(&path)?;
+      let result = SaveFile::read(&path);
+      assert_eq!(
+        result.unwrap_err(),
+        CommandError::OutOfCharacter(format!(
+          "That saved game is from an incompatible version (version {}, expected {}).",
+          version, SAVE_FILE_VERSION
+        ))
+      );
+    }
+    Ok(())
+  }
+}
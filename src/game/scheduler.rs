@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+/// When a scheduled event fires.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Timer {
+  /// A fuse, which fires once when the specified number of turns has
+  /// passed.
+  Fuse {
+    /// The number of turns until the fuse fires.
+    turns_left: u64,
+  },
+  /// A daemon, which fires every turn until it is stopped.
+  Daemon,
+}
+
+/// An event scheduled to fire after some turns.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ScheduledEvent {
+  /// The name of the event, which identifies what happens when it fires.
+  pub name: String,
+  /// When the event fires.
+  pub timer: Timer,
+}
+
+/// The scheduler for timed events: fuses (e.g. "in 5 turns the bomb
+/// explodes") and daemons (e.g. "every turn the lantern's battery drains").
+///
+/// Events are scheduled by name, so that they can be saved with the game;
+/// the game loop decides what each name does when the event fires. See
+/// [`GameLoop::with_event`](crate::game::prelude::GameLoop::with_event).
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Scheduler {
+  /// The scheduled events, in the order in which they were scheduled.
+  pub events: Vec<ScheduledEvent>,
+}
+
+impl Scheduler {
+  /// Create a new scheduler with no events.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Schedule the event to fire once, after the specified number of turns
+  /// (at least one).
+  ///
+  /// Any event of the same name is replaced, so lighting a fuse again resets
+  /// it.
+  pub fn start_fuse(&mut self, name: &str, turns: u64) {
+    self.schedule(
+      name,
+      Timer::Fuse {
+        turns_left: turns.max(1),
+      },
+    );
+  }
+
+  /// Schedule the event to fire every turn until it is stopped.
+  pub fn start_daemon(&mut self, name: &str) {
+    self.schedule(name, Timer::Daemon);
+  }
+
+  /// Stop the event from firing, returning whether it was scheduled.
+  pub fn stop(&mut self, name: &str) -> bool {
+    let count = self.events.len();
+    self.events.retain(|event| event.name != name);
+    self.events.len() != count
+  }
+
+  /// Whether the event is scheduled.
+  pub fn is_scheduled(&self, name: &str) -> bool {
+    self.events.iter().any(|event| event.name == name)
+  }
+
+  /// The number of turns until the fuse of the specified name fires, if it
+  /// is scheduled.
+  pub fn turns_left(&self, name: &str) -> Option<u64> {
+    self.events.iter().find_map(|event| match event.timer {
+      Timer::Fuse { turns_left } if event.name == name => Some(turns_left),
+      _ => None,
+    })
+  }
+
+  /// Advance the scheduler by one turn, returning the names of the events
+  /// that fire, in the order in which they were scheduled.
+  ///
+  /// Fuses that fire are removed; daemons remain.
+  pub fn tick(&mut self) -> Vec<String> {
+    let mut fired = Vec::new();
+    self.events.retain_mut(|event| match &mut event.timer {
+      Timer::Fuse { turns_left } => {
+        *turns_left -= 1;
+        if *turns_left == 0 {
+          fired.push(event.name.clone());
+        }
+        *turns_left > 0
+      },
+      Timer::Daemon => {
+        fired.push(event.name.clone());
+        true
+      },
+    });
+    fired
+  }
+
+  /// Schedule an event, replacing any event of the same name.
+  fn schedule(&mut self, name: &str, timer: Timer) {
+    self.stop(name);
+    self.events.push(ScheduledEvent {
+      name: name.to_string(),
+      timer,
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_tick() {
+    test_utils::init();
+    let mut scheduler = Scheduler::new();
+    scheduler.start_fuse("bomb", 2);
+    scheduler.start_daemon("battery");
+    assert_eq!(scheduler.turns_left("bomb"), Some(2));
+    assert_eq!(scheduler.tick(), vec!["battery"]);
+    assert_eq!(scheduler.turns_left("bomb"), Some(1));
+    assert_eq!(scheduler.tick(), vec!["bomb", "battery"]);
+    assert!(!scheduler.is_scheduled("bomb"));
+    assert_eq!(scheduler.tick(), vec!["battery"]);
+  }
+
+  #[test]
+  fn test_start_fuse_again() {
+    test_utils::init();
+    let mut scheduler = Scheduler::new();
+    scheduler.start_fuse("bomb", 2);
+    scheduler.tick();
+    scheduler.start_fuse("bomb", 0);
+    assert_eq!(scheduler.turns_left("bomb"), Some(1));
+    assert_eq!(scheduler.events.len(), 1);
+  }
+
+  #[test]
+  fn test_stop() {
+    test_utils::init();
+    let mut scheduler = Scheduler::new();
+    scheduler.start_daemon("battery");
+    assert!(scheduler.stop("battery"));
+    assert!(!scheduler.stop("battery"));
+    assert!(scheduler.tick().is_empty());
+  }
+}
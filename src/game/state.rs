@@ -1,17 +1,116 @@
-use crate::command::prelude::Command;
-use std::collections::VecDeque;
+use crate::command::prelude::{Command, CommandError};
+use crate::game::combat::Combatant;
+use crate::game::npc::{Npc, NpcAction, NpcId};
+use crate::game::scheduler::Scheduler;
+use crate::parser::error::which_do_you_mean;
+use crate::parser::prelude::NounPhrase;
+use crate::world::prelude::{ObjectId, World};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::mem;
+use std::path::PathBuf;
 
 /// The game state.
 ///
 /// This struct defines the game state for Saltshore.
-#[derive(Clone, Debug, Default)]
+///
+/// Everything that is not marked `#[serde(skip)]` is written to disk when the
+/// game is saved; the rest is transient and belongs to the current session.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GameState {
   /// Whether the game is finished.
+  #[serde(skip)]
   pub quit_flag: bool,
   /// The input queue.
+  #[serde(skip)]
   pub input_queue: VecDeque<String>,
   /// The command queue.
+  #[serde(skip)]
   pub command_queue: VecDeque<Command>,
+  /// The output queue.
+  #[serde(skip)]
+  pub output_queue: VecDeque<String>,
+  /// The directory in which saved games are kept.
+  #[serde(skip)]
+  pub save_directory: PathBuf,
+  /// The objects the player most recently referred to, which pronouns (e.g.
+  /// "it" or "them") refer to.
+  #[serde(skip)]
+  pub last_objects: Vec<ObjectId>,
+  /// The most recently executed command, which "again" repeats.
+  #[serde(skip)]
+  pub last_command: Option<Command>,
+  /// The last input that failed because of an unknown word, which "oops"
+  /// corrects.
+  #[serde(skip)]
+  pub failed_input: Option<FailedInput>,
+  /// A question the player has been asked, which the next input answers.
+  #[serde(skip)]
+  pub pending_question: Option<PendingQuestion>,
+  /// Snapshots of the game state that can be restored by undoing, oldest
+  /// first.
+  #[serde(skip)]
+  pub undo_history: VecDeque<UndoSnapshot>,
+  /// The player's aliases and macros, keyed by name, with the inputs they
+  /// stand for (e.g. "kk" for "kill kobold with sword").
+  #[serde(default)]
+  pub aliases: BTreeMap<String, Vec<String>>,
+  /// The fuses and daemons that fire as turns pass.
+  #[serde(default)]
+  pub scheduler: Scheduler,
+  /// The player's health and fighting ability.
+  #[serde(default)]
+  pub player: Combatant,
+  /// The non-player characters, which act on their own as turns pass.
+  #[serde(default)]
+  pub npcs: BTreeMap<NpcId, Npc>,
+  /// The game world.
+  pub world: World,
+}
+
+/// A question asked of the player because their input could refer to more
+/// than one object (e.g. "Which do you mean, the brass key or the iron
+/// key?").
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingQuestion {
+  /// The input that prompted the question.
+  pub input: String,
+  /// The noun phrase that could refer to more than one object.
+  pub noun_phrase: NounPhrase,
+  /// The objects the noun phrase could refer to.
+  pub candidates: Vec<ObjectId>,
+}
+
+impl PendingQuestion {
+  /// The question to ask the player.
+  pub fn question(&self, world: &World) -> String {
+    let names: Vec<String> = self
+      .candidates
+      .iter()
+      .filter_map(|id| world.object(id))
+      .map(|object| object.definite_name())
+      .collect();
+    which_do_you_mean(&self.noun_phrase, &names)
+  }
+}
+
+/// Input that failed because of an unknown word (e.g. "take the lanten").
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedInput {
+  /// The input.
+  pub input: String,
+  /// The unknown word (e.g. "lanten").
+  pub word: String,
+}
+
+/// A snapshot of the game state, taken before a command was executed.
+#[derive(Clone, Debug)]
+pub struct UndoSnapshot {
+  /// A description of what was done after the snapshot was taken (normally
+  /// the player's input).
+  pub label: String,
+  /// The game state before the command was executed.
+  pub state: GameState,
 }
 
 impl GameState {
@@ -21,6 +120,18 @@ impl GameState {
       quit_flag: false,
       input_queue: VecDeque::new(),
       command_queue: VecDeque::new(),
+      output_queue: VecDeque::new(),
+      save_directory: PathBuf::new(),
+      last_objects: Vec::new(),
+      last_command: None,
+      failed_input: None,
+      pending_question: None,
+      undo_history: VecDeque::new(),
+      aliases: BTreeMap::new(),
+      scheduler: Scheduler::new(),
+      player: Combatant::default(),
+      npcs: BTreeMap::new(),
+      world: World::new(),
     }
   }
 
@@ -71,11 +182,175 @@ impl GameState {
     self.command_queue.clear();
   }
 
+  /// Enqueue output for the player.
+  pub fn enqueue_output(&mut self, output: String) {
+    self.output_queue.push_back(output);
+  }
+
+  /// Dequeue output for the player.
+  pub fn dequeue_output(&mut self) -> Option<String> {
+    self.output_queue.pop_front()
+  }
+
   /// Clear the input and command queues.
   pub fn clear_input_and_command_queues(&mut self) {
     self.clear_input_queue();
     self.clear_command_queue();
   }
+
+  /// Get the path of the saved game with the specified name.
+  pub fn save_path(&self, name: &str) -> PathBuf {
+    self.save_directory.join(format!("{}.sav", name))
+  }
+
+  /// Replace this game state with another (e.g. a saved game), keeping the
+  /// transient parts of this game state (e.g. the queues) as they are.
+  pub fn restore(&mut self, mut other: GameState) {
+    other.quit_flag = self.quit_flag;
+    other.input_queue = mem::take(&mut self.input_queue);
+    other.command_queue = mem::take(&mut self.command_queue);
+    other.output_queue = mem::take(&mut self.output_queue);
+    other.save_directory = mem::take(&mut self.save_directory);
+    other.undo_history = mem::take(&mut self.undo_history);
+    *self = other;
+  }
+
+  /// Expand an input that starts with an alias or macro into the inputs it
+  /// stands for, or `None` if it doesn't.
+  ///
+  /// Anything after the name is added to the last input, so that with "kk"
+  /// standing for "kill kobold", "kk with sword" is "kill kobold with sword".
+  pub fn expand_alias(&self, input: &str) -> Option<Vec<String>> {
+    let input = input.trim();
+    let (name, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+    let mut inputs = self.aliases.get(&name.to_lowercase())?.clone();
+    if let Some(last) = inputs.last_mut().filter(|_| !rest.trim().is_empty()) {
+      last.push(' ');
+      last.push_str(rest.trim());
+    }
+    Some(inputs)
+  }
+
+  /// Describe the player's surroundings, including any non-player
+  /// characters who can be seen there.
+  pub fn describe_current_room(&self) -> Option<String> {
+    let mut lines = vec![self.world.describe_current_room()?];
+    if self.world.is_lit() {
+      lines.extend(
+        self
+          .npcs
+          .values()
+          .filter(|npc| npc.location == self.world.player_location)
+          .map(|npc| npc.description.clone()),
+      );
+    }
+    Some(lines.join("\n"))
+  }
+
+  /// Find the non-player character in the player's room whose name includes
+  /// all of the words (e.g. "goblin" or "big goblin"), if any. Nobody can
+  /// be found in the dark.
+  pub fn find_npc(&self, words: &[&str]) -> Option<NpcId> {
+    if !self.world.is_lit() {
+      return None;
+    }
+    self
+      .npcs
+      .iter()
+      .filter(|(_, npc)| npc.location == self.world.player_location)
+      .find(|(_, npc)| npc.is_called(words))
+      .map(|(id, _)| id.clone())
+  }
+
+  /// The damage turned aside from each blow by the armor the player is
+  /// carrying.
+  pub fn player_armor(&self) -> u32 {
+    self
+      .world
+      .inventory()
+      .iter()
+      .filter_map(|id| self.world.object(id))
+      .map(|object| object.armor)
+      .sum()
+  }
+
+  /// Let each hostile non-player character in the player's room strike at
+  /// them, failing with [`CommandError::Death`] if the player dies.
+  pub fn update_combat(&mut self) -> Result<(), CommandError> {
+    let armor = self.player_armor();
+    let here = &self.world.player_location;
+    let attackers = self
+      .npcs
+      .values()
+      .filter(|npc| npc.hostile && npc.location == *here)
+      .filter_map(|npc| Some((npc.name.clone(), npc.combatant?.damage)));
+    let mut outputs = Vec::new();
+    let mut result = Ok(());
+    for (name, damage) in attackers {
+      self.player.take_hit(damage, armor);
+      if self.player.is_dead() {
+        result = Err(CommandError::Death(format!("The {} strikes a fatal blow.", name)));
+        break;
+      }
+      outputs.push(format!("The {} hits you.", name));
+    }
+    for output in outputs {
+      self.enqueue_output(output);
+    }
+    result
+  }
+
+  /// Let each non-player character act, telling the player about anything
+  /// they see (e.g. "A goblin enters from the west.").
+  pub fn update_npcs(&mut self) {
+    let ids: Vec<NpcId> = self.npcs.keys().cloned().collect();
+    for id in ids {
+      let action = self.npcs[&id].act(&self.world);
+      let here = self.world.player_location.clone();
+      let npc = self.npcs.get_mut(&id).expect("NPC exists");
+      let output = match action {
+        NpcAction::Stay => None,
+        NpcAction::Emote(emote) => Some(emote).filter(|_| npc.location == here),
+        NpcAction::Move { exit, destination } => {
+          let origin = mem::replace(&mut npc.location, destination);
+          if origin == here {
+            Some(npc.departure(&exit))
+          } else if npc.location == here {
+            Some(npc.arrival(&self.world, &origin, &exit))
+          } else {
+            None
+          }
+        },
+      };
+      if let Some(output) = output.filter(|_| self.world.is_lit()) {
+        self.enqueue_output(output);
+      }
+    }
+  }
+
+  /// Take a snapshot of the game state, which can later be restored.
+  ///
+  /// The snapshot does not include the undo history itself.
+  pub fn snapshot(&mut self) -> GameState {
+    let undo_history = mem::take(&mut self.undo_history);
+    let snapshot = self.clone();
+    self.undo_history = undo_history;
+    snapshot
+  }
+
+  /// Remember a snapshot so that it can be restored by undoing, forgetting
+  /// the oldest snapshots if there are more than `limit` of them.
+  pub fn push_undo_snapshot(&mut self, snapshot: UndoSnapshot, limit: usize) {
+    self.undo_history.push_back(snapshot);
+    while self.undo_history.len() > limit {
+      self.undo_history.pop_front();
+    }
+  }
+
+  /// Take the most recent snapshot from the undo history.
+  pub fn pop_undo_snapshot(&mut self) -> Option<UndoSnapshot> {
+    self.undo_history.pop_back()
+  }
 }
 
 #[cfg(test)]
@@ -84,14 +359,15 @@ mod tests {
   use crate::command::prelude::Command;
   use crate::command::prelude::QuitCommand;
   use crate::test_utils;
+  use crate::world::prelude::{Location, Object, Room};
 
   #[test]
   fn test_quit_flag() {
     test_utils::init();
     let mut game_state = GameState::default();
-    assert_eq!(game_state.quit_flag(), false);
+    assert!(!game_state.quit_flag());
     game_state.set_quit_flag(true);
-    assert_eq!(game_state.quit_flag(), true);
+    assert!(game_state.quit_flag());
   }
 
   #[test]
@@ -149,13 +425,117 @@ mod tests {
     assert_eq!(game_state.command_queue.len(), 0);
   }
 
+  #[test]
+  fn test_enqueue_output() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    game_state.enqueue_output("Taken.".to_string());
+    assert_eq!(game_state.output_queue.len(), 1);
+  }
+
+  #[test]
+  fn test_dequeue_output() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    game_state.enqueue_output("Taken.".to_string());
+    game_state.enqueue_output("Dropped.".to_string());
+    assert_eq!(game_state.dequeue_output(), Some("Taken.".to_string()));
+    assert_eq!(game_state.dequeue_output(), Some("Dropped.".to_string()));
+    assert_eq!(game_state.dequeue_output(), None);
+  }
+
+  #[test]
+  fn test_save_path() {
+    test_utils::init();
+    let game_state = GameState {
+      save_directory: PathBuf::from("saves"),
+      ..GameState::default()
+    };
+    assert_eq!(game_state.save_path("game"), PathBuf::from("saves/game.sav"));
+  }
+
+  #[test]
+  fn test_restore() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.enqueue_input("look".to_string());
+    game_state.save_directory = PathBuf::from("saves");
+    let mut other = test_utils::test_state();
+    other.world.player_location = "north_of_house".to_string();
+    other.enqueue_input("inventory".to_string());
+    game_state.restore(other);
+    assert_eq!(game_state.world.player_location, "north_of_house");
+    assert_eq!(game_state.input_queue, vec!["look".to_string()]);
+    assert_eq!(game_state.save_directory, PathBuf::from("saves"));
+  }
+
+  #[test]
+  fn test_snapshot() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    let snapshot = game_state.snapshot();
+    game_state.push_undo_snapshot(
+      UndoSnapshot {
+        label: "look".to_string(),
+        state: snapshot,
+      },
+      10,
+    );
+    let snapshot = game_state.snapshot();
+    assert!(snapshot.undo_history.is_empty());
+    assert_eq!(snapshot.world, game_state.world);
+    assert_eq!(game_state.undo_history.len(), 1);
+  }
+
+  #[test]
+  fn test_undo_history_limit() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    for label in ["one", "two", "three"] {
+      let snapshot = UndoSnapshot {
+        label: label.to_string(),
+        state: game_state.snapshot(),
+      };
+      game_state.push_undo_snapshot(snapshot, 2);
+    }
+    assert_eq!(game_state.undo_history.len(), 2);
+    assert_eq!(game_state.pop_undo_snapshot().unwrap().label, "three");
+    assert_eq!(game_state.pop_undo_snapshot().unwrap().label, "two");
+    assert!(game_state.pop_undo_snapshot().is_none());
+  }
+
+  #[test]
+  fn test_pending_question() {
+    test_utils::init();
+    let mut world = test_utils::test_world();
+    world.add_object("brass_key", Object::new("brass key", "").at(Location::Player));
+    world.add_object("iron_key", Object::new("iron key", "").at(Location::Player));
+    world.add_object("skeleton_key", Object::new("skeleton key", "").at(Location::Player));
+    let mut question = PendingQuestion {
+      input: "take key".to_string(),
+      noun_phrase: NounPhrase::new(&["key"]),
+      candidates: vec!["brass_key".to_string(), "iron_key".to_string()],
+    };
+    assert_eq!(
+      question.question(&world),
+      "Which do you mean, the brass key or the iron key?"
+    );
+    question.candidates.push("skeleton_key".to_string());
+    assert_eq!(
+      question.question(&world),
+      "Which do you mean, the brass key, the iron key or the skeleton key?"
+    );
+  }
+
   #[test]
   fn test_new() {
     test_utils::init();
     let game_state = GameState::new();
-    assert_eq!(game_state.quit_flag(), false);
+    assert!(!game_state.quit_flag());
     assert_eq!(game_state.input_queue.len(), 0);
     assert_eq!(game_state.command_queue.len(), 0);
+    assert_eq!(game_state.output_queue.len(), 0);
+    assert!(game_state.world.rooms.is_empty());
   }
 
   #[test]
@@ -167,4 +547,128 @@ mod tests {
     game_state.clear_command_queue();
     assert_eq!(game_state.command_queue.len(), 0);
   }
+
+  #[test]
+  fn test_expand_alias() {
+    test_utils::init();
+    let mut game_state = GameState::default();
+    game_state
+      .aliases
+      .insert("kk".to_string(), vec!["kill kobold".to_string()]);
+    game_state.aliases.insert(
+      "dig".to_string(),
+      vec!["take shovel".to_string(), "dig".to_string(), "drop shovel".to_string()],
+    );
+    assert_eq!(game_state.expand_alias("KK"), Some(vec!["kill kobold".to_string()]));
+    assert_eq!(
+      game_state.expand_alias("kk with  sword "),
+      Some(vec!["kill kobold with  sword".to_string()])
+    );
+    assert_eq!(
+      game_state.expand_alias("dig"),
+      Some(vec![
+        "take shovel".to_string(),
+        "dig".to_string(),
+        "drop shovel".to_string()
+      ])
+    );
+    assert_eq!(game_state.expand_alias("kkk"), None);
+  }
+
+  #[test]
+  fn test_describe_current_room() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.npcs.insert(
+      "goblin".to_string(),
+      Npc::new("goblin", "A goblin is sharpening its knife here.").at("west_of_house"),
+    );
+    let description = game_state.describe_current_room().unwrap();
+    assert!(description.starts_with(&game_state.world.describe_current_room().unwrap()));
+    assert!(description.ends_with("\nA goblin is sharpening its knife here."));
+    game_state.world.player_location = "north_of_house".to_string();
+    assert!(!game_state.describe_current_room().unwrap().contains("goblin"));
+  }
+
+  #[test]
+  fn test_update_npcs() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world.add_room(
+      "north_of_house",
+      Room::new("North of House", "").with_exit("south", "west_of_house"),
+    );
+    game_state.npcs.insert(
+      "goblin".to_string(),
+      Npc::new("goblin", "").at("north_of_house").follow(),
+    );
+    game_state.npcs.insert(
+      "orc".to_string(),
+      Npc::new("orc", "").at("west_of_house").idle(&["The orc belches."], 1),
+    );
+    game_state.update_npcs();
+    assert_eq!(game_state.npcs["goblin"].location, "west_of_house");
+    assert_eq!(
+      game_state.output_queue,
+      vec!["A goblin enters from the north.", "The orc belches."]
+    );
+    // Nothing can be seen in the dark.
+    game_state.output_queue.clear();
+    game_state.world.rooms.get_mut("west_of_house").unwrap().dark = true;
+    game_state.world.object_mut("lantern").unwrap().location = Location::Nowhere;
+    game_state.world.player_location = "north_of_house".to_string();
+    game_state.world.rooms.get_mut("north_of_house").unwrap().dark = true;
+    game_state.update_npcs();
+    assert_eq!(game_state.npcs["goblin"].location, "north_of_house");
+    assert!(game_state.output_queue.is_empty());
+  }
+
+  #[test]
+  fn test_find_npc() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state
+      .npcs
+      .insert("goblin".to_string(), Npc::new("big goblin", "").at("west_of_house"));
+    game_state
+      .npcs
+      .insert("orc".to_string(), Npc::new("orc", "").at("north_of_house"));
+    assert_eq!(game_state.find_npc(&["big", "goblin"]), Some("goblin".to_string()));
+    assert_eq!(game_state.find_npc(&["small", "goblin"]), None);
+    assert_eq!(game_state.find_npc(&["orc"]), None);
+    // Nobody can be found in the dark.
+    game_state.world.rooms.get_mut("west_of_house").unwrap().dark = true;
+    game_state.world.object_mut("lantern").unwrap().location = Location::Nowhere;
+    assert_eq!(game_state.find_npc(&["goblin"]), None);
+  }
+
+  #[test]
+  fn test_update_combat() {
+    test_utils::init();
+    let mut game_state = test_utils::test_state();
+    game_state.world = game_state
+      .world
+      .with_object("shield", Object::new("shield", "").at(Location::Player).with_armor(1));
+    game_state.npcs.insert(
+      "goblin".to_string(),
+      Npc::new("goblin", "")
+        .at("west_of_house")
+        .with_combatant(Combatant::new(5, 4, 0)),
+    );
+    assert_eq!(game_state.player_armor(), 1);
+    // Only hostile characters strike.
+    assert_eq!(game_state.update_combat(), Ok(()));
+    assert_eq!(game_state.player.hit_points, 10);
+    game_state.npcs.get_mut("goblin").unwrap().hostile = true;
+    for _ in 0..3 {
+      assert_eq!(game_state.update_combat(), Ok(()));
+    }
+    assert_eq!(game_state.player.hit_points, 1);
+    assert_eq!(game_state.dequeue_output(), Some("The goblin hits you.".to_string()));
+    assert_eq!(
+      game_state.update_combat(),
+      Err(CommandError::Death("The goblin strikes a fatal blow.".to_string()))
+    );
+    assert!(game_state.player.is_dead());
+  }
 }
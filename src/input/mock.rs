@@ -103,7 +103,7 @@ mod tests {
   fn test_eof() {
     let mut input = InputMock::new(vec!["test".to_string()]);
     let mut buf = [0; 4];
-    input.read(&mut buf).unwrap();
+    assert_eq!(input.read(&mut buf).unwrap(), 4);
     assert_eq!(input.read(&mut buf).unwrap(), 0);
   }
 
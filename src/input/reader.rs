@@ -1,18 +1,66 @@
 use crate::input::error::InputError;
 use crate::input::mock::InputMock;
+use crate::parser::action::QUOTE;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader, StdinLock};
 
+/// The characters that separate commands by default.
+pub const DEFAULT_SEPARATORS: &[char] = &[';', '.'];
+
+/// The words that separate commands by default.
+pub const DEFAULT_SEPARATOR_WORDS: &[&str] = &["then"];
+
+/// The character that escapes separators by default.
+pub const DEFAULT_ESCAPE: char = '\\';
+
 /// An input source that reads from a generic reader.
+///
+/// A line of input can hold several commands (e.g. "n. e. take lamp" or
+/// "open door then go north"), which are split apart by separator characters
+/// and words. Quoted text is never split, and a separator can be escaped to
+/// keep it. The separators and the escape can be configured, since different
+/// games want different conventions; e.g. a game might add ',' as a
+/// separator, at the cost of lists like "take lamp, key and rope". The quote
+/// is always the parser's [`QUOTE`], so that quoted text reaches the parser
+/// whole.
 #[derive(Debug)]
 pub struct InputReader<R> {
   reader: R,
+  /// The characters that separate commands.
+  separators: Vec<char>,
+  /// The words that separate commands, in lowercase.
+  separator_words: Vec<String>,
+  /// The character that makes the following character literal, if any.
+  escape: Option<char>,
 }
 
 impl<R: BufRead> InputReader<R> {
   /// Create a new InputReader.
   pub fn new(reader: R) -> Self {
-    Self { reader }
+    Self {
+      reader,
+      separators: DEFAULT_SEPARATORS.to_vec(),
+      separator_words: DEFAULT_SEPARATOR_WORDS.iter().map(|word| word.to_string()).collect(),
+      escape: Some(DEFAULT_ESCAPE),
+    }
+  }
+
+  /// Use these characters to separate commands.
+  pub fn with_separators(mut self, separators: &[char]) -> Self {
+    self.separators = separators.to_vec();
+    self
+  }
+
+  /// Use these words to separate commands.
+  pub fn with_separator_words(mut self, separator_words: &[&str]) -> Self {
+    self.separator_words = separator_words.iter().map(|word| word.to_lowercase()).collect();
+    self
+  }
+
+  /// Use this character, if any, to escape separators.
+  pub fn with_escape(mut self, escape: Option<char>) -> Self {
+    self.escape = escape;
+    self
   }
 
   /// Fetch input from the reader.
@@ -28,9 +76,9 @@ impl<R: BufRead> InputReader<R> {
 
   /// Read a list of potential commands from the reader.
   ///
-  /// The player's input can be split by semi-colons to allow for multiple
-  /// commands. Of course, we cannot treat the input as a command until we
-  /// have parsed it.
+  /// The player's input is split into separate commands; see
+  /// [`InputReader::split`]. Of course, we cannot treat the input as a
+  /// command until we have parsed it.
   pub fn read_inputs(&mut self) -> Result<Option<Vec<String>>, InputError> {
     let mut input = String::new();
     let bytes_read = self.reader.read_line(&mut input)?;
@@ -38,8 +86,86 @@ impl<R: BufRead> InputReader<R> {
       // If no bytes were read, the reader has reached the end of the input.
       return Ok(None);
     }
-    let lines = input.trim().split(';').map(|s| s.to_string()).collect();
-    Ok(Some(lines))
+    let mut inputs = self.split(&input);
+    if inputs.is_empty() {
+      // Keep a blank line, so that the player is told it wasn't understood.
+      inputs.push(String::new());
+    }
+    Ok(Some(inputs))
+  }
+
+  /// Split a line of input into separate commands.
+  ///
+  /// The line is split at each separator character and separator word,
+  /// except inside quoted text (which keeps its quotes) or where the
+  /// separator is escaped (which drops the escape). The escape only escapes
+  /// separators, quotes and itself; anywhere else (e.g. "a\b") it is kept.
+  /// Empty commands are dropped.
+  pub fn split(&self, line: &str) -> Vec<String> {
+    let mut inputs = Vec::new();
+    let mut current = String::new();
+    // Where the word being read starts in `current`, and whether any of it
+    // was escaped (in which case it can't be a separator word).
+    let mut word_start = 0;
+    let mut word_escaped = false;
+    let mut quoted = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+      if !quoted && Some(c) == self.escape && self.escapes(chars.as_str()) {
+        current.extend(chars.next());
+        word_escaped = true;
+      } else if quoted {
+        current.push(c);
+        quoted = c != QUOTE;
+      } else if c == QUOTE {
+        current.push(c);
+        quoted = true;
+      } else if self.separators.contains(&c) || c.is_whitespace() {
+        let is_separator_word = !word_escaped && self.is_separator_word(&current[word_start..]);
+        if is_separator_word {
+          current.truncate(word_start);
+        }
+        if is_separator_word || self.separators.contains(&c) {
+          inputs.push(current.trim().to_string());
+          current.clear();
+        } else {
+          current.push(c);
+        }
+        word_start = current.len();
+        word_escaped = false;
+      } else {
+        current.push(c);
+      }
+    }
+    if word_escaped || !self.is_separator_word(&current[word_start..]) {
+      inputs.push(current.trim().to_string());
+    }
+    inputs.retain(|input| !input.is_empty());
+    inputs
+  }
+
+  /// Whether an escape followed by the rest of the line escapes something:
+  /// a separator character, a quote, another escape, or a separator word.
+  fn escapes(&self, rest: &str) -> bool {
+    let Some(next) = rest.chars().next() else {
+      return false;
+    };
+    if self.separators.contains(&next) || next == QUOTE || Some(next) == self.escape {
+      return true;
+    }
+    let word = rest
+      .split(|c: char| c.is_whitespace() || self.separators.contains(&c))
+      .next()
+      .unwrap_or_default();
+    self.is_separator_word(word)
+  }
+
+  /// Whether the word separates commands.
+  fn is_separator_word(&self, word: &str) -> bool {
+    self
+      .separator_words
+      .iter()
+      .any(|separator| separator.eq_ignore_ascii_case(word))
   }
 }
 
@@ -104,6 +230,60 @@ mod tests {
     assert_eq!(input, "test");
   }
 
+  #[test]
+  fn test_read_inputs() {
+    test_utils::init();
+    let mut input_source = MockReader::default();
+    input_source.add_line("n. e; take lamp then open door".to_string());
+    input_source.add_line("".to_string());
+    assert_eq!(
+      input_source.read_inputs().unwrap().unwrap(),
+      vec!["n", "e", "take lamp", "open door"]
+    );
+    assert_eq!(input_source.read_inputs().unwrap().unwrap(), vec![""]);
+    assert!(input_source.read_inputs().unwrap().is_none());
+  }
+
+  #[test]
+  fn test_split() {
+    test_utils::init();
+    let input_source = MockReader::default();
+    for (line, expected) in [
+      ("look", vec!["look"]),
+      ("take the lamp.", vec!["take the lamp"]),
+      ("n.e.  THEN s", vec!["n", "e", "s"]),
+      ("open door then go north", vec!["open door", "go north"]),
+      ("take lamp, key and rope", vec!["take lamp, key and rope"]),
+      (
+        "say \"hello. then goodbye\"; n",
+        vec!["say \"hello. then goodbye\"", "n"],
+      ),
+      ("write 3\\.14 on paper", vec!["write 3.14 on paper"]),
+      ("say \\then", vec!["say then"]),
+      ("say \\\"hi", vec!["say \"hi"]),
+      ("write a\\b", vec!["write a\\b"]),
+      ("write a\\\\b", vec!["write a\\b"]),
+      ("write \\thenceforth", vec!["write \\thenceforth"]),
+      ("then then;;. ", vec![]),
+      ("thenceforth north", vec!["thenceforth north"]),
+    ] {
+      assert_eq!(input_source.split(line), expected, "{}", line);
+    }
+  }
+
+  #[test]
+  fn test_split_configured() {
+    test_utils::init();
+    let input_source = MockReader::default()
+      .with_separators(&[','])
+      .with_separator_words(&["AND"])
+      .with_escape(Some('^'));
+    assert_eq!(
+      input_source.split("n, e and say \"a, b\" then s\\, w^, x ^and y^z"),
+      vec!["n", "e", "say \"a, b\" then s\\", "w, x and y^z"]
+    );
+  }
+
   #[test]
   fn test_eof() {
     test_utils::init();
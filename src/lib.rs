@@ -44,6 +44,8 @@ pub mod input;
 pub mod output;
 /// Parser for player input.
 pub mod parser;
+/// The game world: rooms, exits, and so forth.
+pub mod world;
 
 /// Prelude for the library.
 pub mod prelude {
@@ -52,13 +54,17 @@ pub mod prelude {
   pub use crate::input::prelude::*;
   pub use crate::output::prelude::*;
   pub use crate::parser::prelude::*;
+  pub use crate::world::prelude::*;
 }
 
+/// Utilities shared by the unit tests.
 #[cfg(test)]
 pub mod test_utils {
 
   #[allow(unused_imports)]
   use super::*;
+  use crate::game::prelude::GameState;
+  use crate::world::prelude::*;
   use std::env;
 
   /// Call this function at the beginning of each test.
@@ -68,4 +74,64 @@ pub mod test_utils {
     // Enable printing messages to the console.
     env::set_var("RUST_TEST_NOCAPTURE", "1");
   }
+
+  /// A small world for tests to play around in.
+  pub fn test_world() -> World {
+    World::new()
+      .with_room(
+        "west_of_house",
+        Room::new("West of House", "You are standing in an open field.")
+          .with_exit("north", "north_of_house")
+          .with_exit("south", "nowhere"),
+      )
+      .with_room(
+        "north_of_house",
+        Room::new("North of House", "You are facing the north side of a white house."),
+      )
+      .with_object(
+        "mailbox",
+        Object::new("small mailbox", "It's a small mailbox.")
+          .at(Location::Room("west_of_house".to_string()))
+          .fixed()
+          .container()
+          .with_capacity(2),
+      )
+      .with_object(
+        "leaflet",
+        Object::new("leaflet", "WELCOME TO SORK!").at(Location::In("mailbox".to_string())),
+      )
+      .with_object(
+        "table",
+        Object::new("wooden table", "A sturdy wooden table.")
+          .at(Location::Room("west_of_house".to_string()))
+          .fixed()
+          .supporter(),
+      )
+      .with_object(
+        "box",
+        Object::new("wooden box", "A small wooden box.")
+          .at(Location::Room("west_of_house".to_string()))
+          .container()
+          .closed(),
+      )
+      .with_object(
+        "lantern",
+        Object::new("brass lantern", "A battery-powered brass lantern.")
+          .at(Location::Player)
+          .with_nouns(&["lamp", "light"]),
+      )
+      .with_object(
+        "sword",
+        Object::new("elvish sword", "An elvish sword.").at(Location::Room("north_of_house".to_string())),
+      )
+      .with_player_location("west_of_house")
+  }
+
+  /// A game state containing the test world.
+  pub fn test_state() -> GameState {
+    GameState {
+      world: test_world(),
+      ..GameState::default()
+    }
+  }
 }
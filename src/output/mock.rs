@@ -37,15 +37,15 @@ mod tests {
   #[test]
   fn test_write() {
     let mut output = OutputMock::new();
-    output.write(b"test").unwrap();
+    output.write_all(b"test").unwrap();
     assert_eq!(output.output, vec!["test"]);
   }
 
   #[test]
   fn test_write_multiple() {
     let mut output = OutputMock::new();
-    output.write(b"test").unwrap();
-    output.write(b"test2").unwrap();
+    output.write_all(b"test").unwrap();
+    output.write_all(b"test2").unwrap();
     assert_eq!(output.output, vec!["test", "test2"]);
   }
 
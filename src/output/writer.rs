@@ -104,7 +104,7 @@ mod tests {
   #[test]
   fn test_write_stdout() {
     test_utils::init();
-    let mut sink = StdoutWriter::new(std::io::stdout());
+    let mut sink = StdoutWriter::new(io::stdout());
     // sink.writeln("test").unwrap();
     sink.flush().unwrap();
   }
@@ -112,7 +112,7 @@ mod tests {
   #[test]
   fn test_write_stderr() {
     test_utils::init();
-    let mut sink = StderrWriter::new(std::io::stderr());
+    let mut sink = StderrWriter::new(io::stderr());
     // sink.writeln("test").unwrap();
     sink.flush().unwrap();
   }
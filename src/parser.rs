@@ -1,11 +1,19 @@
+/// Structured actions parsed from the player's input.
+pub mod action;
 /// Error handling for the parser module.
 pub mod error;
+/// Verb synonyms and abbreviations.
+pub mod lexicon;
 /// Actual parser implementation.
 #[allow(clippy::module_inception)]
 pub mod parser;
+/// Spelling correction for unknown words.
+pub mod spelling;
 
 /// The prelude for the parser module.
 pub mod prelude {
+  pub use crate::parser::action::{Action, NounPhrase};
   pub use crate::parser::error::ParserError;
+  pub use crate::parser::lexicon::Lexicon;
   pub use crate::parser::parser::Parser;
 }
@@ -0,0 +1,282 @@
+use std::fmt;
+
+/// Articles, which are ignored wherever they appear.
+pub const ARTICLES: &[&str] = &["a", "an", "the"];
+
+/// Words that carry no meaning for the parser (e.g. "quit now").
+pub const NOISE_WORDS: &[&str] = &["please", "now"];
+
+/// Words that join noun phrases together (e.g. "lamp and sword" or "all but
+/// sword"). Commas are treated as "and".
+pub const CONJUNCTIONS: &[&str] = &["and", "but", "except"];
+
+/// Pronouns, which refer to the objects the player last referred to.
+pub const PRONOUNS: &[&str] = &["it", "them", "him", "her"];
+
+/// Prepositions, which separate the direct object from the indirect object.
+pub const PREPOSITIONS: &[&str] = &[
+  "about", "at", "behind", "from", "in", "inside", "into", "off", "on", "onto", "through", "to", "under", "using",
+  "with",
+];
+
+/// The character that quotes free text (e.g. `say "Hello, Sailor!"`).
+pub const QUOTE: char = '"';
+
+/// A noun phrase: any adjectives followed by a noun (e.g. "small brass key").
+///
+/// Articles are not included.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NounPhrase {
+  /// The words of the noun phrase, in lowercase.
+  pub words: Vec<String>,
+}
+
+impl NounPhrase {
+  /// Create a new noun phrase from its words.
+  pub fn new(words: &[&str]) -> Self {
+    Self {
+      words: words.iter().map(|word| word.to_string()).collect(),
+    }
+  }
+
+  /// The words of the noun phrase, borrowed.
+  pub fn words(&self) -> Vec<&str> {
+    self.words.iter().map(String::as_str).collect()
+  }
+
+  /// Replace every part of a compound noun phrase (e.g. "key and lamp") that
+  /// matches `old` with `new`.
+  pub fn replace(&self, old: &NounPhrase, new: &NounPhrase) -> NounPhrase {
+    let mut words = Vec::new();
+    let mut rest = self.words.as_slice();
+    loop {
+      let end = rest
+        .iter()
+        .position(|word| CONJUNCTIONS.contains(&word.as_str()))
+        .unwrap_or(rest.len());
+      let part = &rest[..end];
+      words.extend_from_slice(if part == old.words.as_slice() { &new.words } else { part });
+      match rest.get(end) {
+        Some(conjunction) => {
+          words.push(conjunction.clone());
+          rest = &rest[end + 1..];
+        },
+        None => break,
+      }
+    }
+    NounPhrase { words }
+  }
+}
+
+impl fmt::Display for NounPhrase {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(formatter, "{}", self.words.join(" "))
+  }
+}
+
+/// A structured action, as understood from the player's input.
+///
+/// An action follows the grammar `verb [direct object] [preposition
+/// [indirect object]]`, e.g. "put the small brass key in the wooden box" or
+/// "unlock door with key". Quoted text (e.g. `write "xyzzy" on paper`) stands
+/// apart from this grammar, keeping its case and punctuation.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Action {
+  /// The verb (e.g. "put").
+  pub verb: String,
+  /// The direct object (e.g. "small brass key").
+  pub direct_object: Option<NounPhrase>,
+  /// The preposition (e.g. "in").
+  pub preposition: Option<String>,
+  /// The indirect object (e.g. "wooden box").
+  pub indirect_object: Option<NounPhrase>,
+  /// Any quoted text, without its quotes (e.g. "xyzzy").
+  pub text: Option<String>,
+}
+
+impl Action {
+  /// Parse the player's input into an action.
+  ///
+  /// Input is case-insensitive, trailing punctuation is ignored, and so are
+  /// articles and noise words, except within quoted text. Returns `None` if
+  /// there is no verb.
+  pub fn parse(input: &str) -> Option<Self> {
+    let (input, text) = split_text(input);
+    let tokens = tokenize(&input);
+    let (verb, rest) = tokens.split_first()?;
+    let rest: Vec<&str> = rest.iter().map(String::as_str).collect();
+    let (direct, preposition, indirect) = match rest.iter().position(|word| PREPOSITIONS.contains(word)) {
+      Some(position) => (
+        &rest[..position],
+        Some(rest[position].to_string()),
+        &rest[position + 1..],
+      ),
+      None => (rest.as_slice(), None, &[][..]),
+    };
+    let noun_phrase = |words: &[&str]| (!words.is_empty()).then(|| NounPhrase::new(words));
+    Some(Self {
+      verb: verb.clone(),
+      direct_object: noun_phrase(direct),
+      preposition,
+      indirect_object: noun_phrase(indirect),
+      text,
+    })
+  }
+
+  /// Whether the action consists of the verb alone.
+  pub fn is_bare(&self) -> bool {
+    self.direct_object.is_none() && self.preposition.is_none() && self.indirect_object.is_none() && self.text.is_none()
+  }
+}
+
+/// Split the first quoted text out of the input, leaving the rest of the
+/// input around it.
+///
+/// An unclosed quote runs to the end of the input.
+fn split_text(input: &str) -> (String, Option<String>) {
+  let Some((before, quoted)) = input.split_once(QUOTE) else {
+    return (input.to_string(), None);
+  };
+  let (text, after) = quoted.split_once(QUOTE).unwrap_or((quoted, ""));
+  (format!("{} {}", before, after), Some(text.to_string()))
+}
+
+/// Split the input into lowercase words, dropping trailing punctuation,
+/// articles, and noise words.
+pub fn tokenize(input: &str) -> Vec<String> {
+  input
+    .replace(',', " and ")
+    .split_whitespace()
+    .map(|word| word.trim_end_matches(['.', '!', '?']).to_lowercase())
+    .filter(|word| !word.is_empty() && !ARTICLES.contains(&word.as_str()) && !NOISE_WORDS.contains(&word.as_str()))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_parse_verb() {
+    test_utils::init();
+    let action = Action::parse("QUIT now").unwrap();
+    assert_eq!(action.verb, "quit");
+    assert!(action.is_bare());
+    assert!(Action::parse("  ").is_none());
+    assert!(Action::parse("the").is_none());
+  }
+
+  #[test]
+  fn test_parse_direct_object() {
+    test_utils::init();
+    assert_eq!(
+      Action::parse("Take the brass lantern.").unwrap(),
+      Action {
+        verb: "take".to_string(),
+        direct_object: Some(NounPhrase::new(&["brass", "lantern"])),
+        ..Action::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_parse_indirect_object() {
+    test_utils::init();
+    assert_eq!(
+      Action::parse("put the small brass key in the wooden box").unwrap(),
+      Action {
+        verb: "put".to_string(),
+        direct_object: Some(NounPhrase::new(&["small", "brass", "key"])),
+        preposition: Some("in".to_string()),
+        indirect_object: Some(NounPhrase::new(&["wooden", "box"])),
+        ..Action::default()
+      }
+    );
+    assert_eq!(
+      Action::parse("unlock door with key").unwrap(),
+      Action {
+        verb: "unlock".to_string(),
+        direct_object: Some(NounPhrase::new(&["door"])),
+        preposition: Some("with".to_string()),
+        indirect_object: Some(NounPhrase::new(&["key"])),
+        ..Action::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_parse_preposition_only() {
+    test_utils::init();
+    assert_eq!(
+      Action::parse("look in the mailbox").unwrap(),
+      Action {
+        verb: "look".to_string(),
+        preposition: Some("in".to_string()),
+        indirect_object: Some(NounPhrase::new(&["mailbox"])),
+        ..Action::default()
+      }
+    );
+    assert_eq!(
+      Action::parse("go in").unwrap(),
+      Action {
+        verb: "go".to_string(),
+        preposition: Some("in".to_string()),
+        ..Action::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_parse_text() {
+    test_utils::init();
+    assert_eq!(
+      Action::parse("Write \"XYZZY, plugh.\" on the paper").unwrap(),
+      Action {
+        verb: "write".to_string(),
+        preposition: Some("on".to_string()),
+        indirect_object: Some(NounPhrase::new(&["paper"])),
+        text: Some("XYZZY, plugh.".to_string()),
+        ..Action::default()
+      }
+    );
+    let action = Action::parse("yell \"Hello, Sailor!").unwrap();
+    assert_eq!(action.text, Some("Hello, Sailor!".to_string()));
+    assert!(!action.is_bare());
+    assert!(Action::parse("\"hello\"").is_none());
+  }
+
+  #[test]
+  fn test_parse_compound() {
+    test_utils::init();
+    assert_eq!(
+      Action::parse("take lamp, brass key and rope").unwrap().direct_object,
+      Some(NounPhrase::new(&["lamp", "and", "brass", "key", "and", "rope"]))
+    );
+    assert_eq!(
+      Action::parse("drop all but the sword").unwrap().direct_object,
+      Some(NounPhrase::new(&["all", "but", "sword"]))
+    );
+  }
+
+  #[test]
+  fn test_noun_phrase_replace() {
+    test_utils::init();
+    let noun_phrase = NounPhrase::new(&["key", "and", "lamp", "but", "key"]);
+    assert_eq!(
+      noun_phrase.replace(&NounPhrase::new(&["key"]), &NounPhrase::new(&["iron", "key"])),
+      NounPhrase::new(&["iron", "key", "and", "lamp", "but", "iron", "key"])
+    );
+    assert_eq!(
+      noun_phrase.replace(&NounPhrase::new(&["rope"]), &NounPhrase::new(&["long", "rope"])),
+      noun_phrase
+    );
+  }
+
+  #[test]
+  fn test_noun_phrase_display() {
+    test_utils::init();
+    assert_eq!(NounPhrase::new(&["brass", "lantern"]).to_string(), "brass lantern");
+  }
+}
@@ -1,3 +1,5 @@
+use crate::parser::action::NounPhrase;
+use crate::world::prelude::ObjectId;
 use thiserror::Error as ThisError;
 
 /// An error that can occur when parsing player input.
@@ -6,9 +8,172 @@ use thiserror::Error as ThisError;
 /// or unexpected input. This is distinct from errors that occur due to the
 /// mechanical process of reading input, such as file errors or network
 /// connection errors.
-#[derive(ThisError, Debug, Clone)]
+///
+/// Each variant's message is written for the player, since it is shown to
+/// them as is.
+#[derive(ThisError, Debug, Clone, PartialEq)]
 pub enum ParserError {
-  /// Invalid input.
-  #[error("Invalid input: {0}")]
+  /// Invalid input, which doesn't fit any of the more specific variants.
+  #[error("I'm sorry, I don't understand '{0}'.")]
   InvalidInput(String),
+  /// The verb is not one the parser knows.
+  #[error("I don't know the verb '{word}'.{}", did_you_mean(.suggestion))]
+  UnknownVerb {
+    /// The unknown verb.
+    word: String,
+    /// The verb the player most likely meant, if any.
+    suggestion: Option<String>,
+  },
+  /// A word in a noun phrase doesn't refer to any object in the world.
+  #[error("I don't know the word '{word}'.{}", did_you_mean(.suggestion))]
+  UnknownNoun {
+    /// The unknown word.
+    word: String,
+    /// The word the player most likely meant, if any.
+    suggestion: Option<String>,
+  },
+  /// The noun phrase refers to an object, but none that is in scope.
+  #[error("You can't see any {0} here.")]
+  NotInScope(NounPhrase),
+  /// A pronoun doesn't refer to anything in scope.
+  #[error("I'm not sure what '{0}' refers to.")]
+  UnresolvedPronoun(String),
+  /// The verb needs a direct object (e.g. "take" on its own).
+  #[error("What do you want to {0}?")]
+  MissingDirectObject(String),
+  /// The verb needs an indirect object (e.g. "put lamp in").
+  #[error("What do you want to {verb} {preposition}?")]
+  MissingIndirectObject {
+    /// The verb.
+    verb: String,
+    /// The preposition that should introduce the indirect object.
+    preposition: String,
+  },
+  /// The verb can't be used with the preposition (e.g. "take lamp with
+  /// sword").
+  #[error("You can't {verb} {preposition} anything.")]
+  BadPreposition {
+    /// The verb.
+    verb: String,
+    /// The preposition.
+    preposition: String,
+  },
+  /// The player said "oops" without having just mistyped a word.
+  #[error("There was no word to replace.")]
+  NothingToCorrect,
+  /// The verb can only act upon one object at a time.
+  #[error("You can't {0} more than one thing at a time.")]
+  TooManyObjects(String),
+  /// The player's words don't refer to any objects at all (e.g. "take all"
+  /// in an empty room).
+  #[error("There is nothing to {0}.")]
+  NoObjects(String),
+  /// The player's words could refer to more than one object.
+  #[error("{}", which_do_you_mean(.noun_phrase, .names))]
+  AmbiguousNounPhrase {
+    /// The input.
+    input: String,
+    /// The noun phrase that could refer to more than one object.
+    noun_phrase: NounPhrase,
+    /// The objects the noun phrase could refer to.
+    candidates: Vec<ObjectId>,
+    /// The names of those objects, with definite articles (e.g. "the brass
+    /// key"), in the same order.
+    names: Vec<String>,
+  },
+}
+
+impl ParserError {
+  /// The word the parser didn't know, if that's what went wrong.
+  pub fn unknown_word(&self) -> Option<&str> {
+    match self {
+      ParserError::UnknownVerb { word, .. } | ParserError::UnknownNoun { word, .. } => Some(word),
+      _ => None,
+    }
+  }
+
+  /// The word the player most likely meant instead of the unknown word.
+  pub fn suggestion(&self) -> Option<&str> {
+    match self {
+      ParserError::UnknownVerb { suggestion, .. } | ParserError::UnknownNoun { suggestion, .. } => {
+        suggestion.as_deref()
+      },
+      _ => None,
+    }
+  }
+}
+
+/// A suggestion to append to a message about an unknown word.
+fn did_you_mean(suggestion: &Option<String>) -> String {
+  match suggestion {
+    Some(suggestion) => format!(" Did you mean '{}'?", suggestion),
+    None => String::new(),
+  }
+}
+
+/// Ask the player which of the named objects they mean (e.g. "Which do you
+/// mean, the brass key or the iron key?").
+pub(crate) fn which_do_you_mean(noun_phrase: &NounPhrase, names: &[String]) -> String {
+  match names {
+    [rest @ .., last] if !rest.is_empty() => format!("Which do you mean, {} or {}?", rest.join(", "), last),
+    _ => format!("Which {} do you mean?", noun_phrase),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_messages() {
+    test_utils::init();
+    assert_eq!(
+      ParserError::MissingDirectObject("take".to_string()).to_string(),
+      "What do you want to take?"
+    );
+    assert_eq!(
+      ParserError::NotInScope(NounPhrase::new(&["elvish", "sword"])).to_string(),
+      "You can't see any elvish sword here."
+    );
+    assert_eq!(
+      ParserError::MissingIndirectObject {
+        verb: "put".to_string(),
+        preposition: "in".to_string()
+      }
+      .to_string(),
+      "What do you want to put in?"
+    );
+    assert_eq!(
+      ParserError::AmbiguousNounPhrase {
+        input: "take key".to_string(),
+        noun_phrase: NounPhrase::new(&["key"]),
+        candidates: vec!["brass_key".to_string(), "rusty_key".to_string()],
+        names: vec!["the brass key".to_string(), "the rusty key".to_string()],
+      }
+      .to_string(),
+      "Which do you mean, the brass key or the rusty key?"
+    );
+  }
+
+  #[test]
+  fn test_unknown_word_messages() {
+    test_utils::init();
+    let error = ParserError::UnknownNoun {
+      word: "lanten".to_string(),
+      suggestion: Some("lantern".to_string()),
+    };
+    assert_eq!(
+      error.to_string(),
+      "I don't know the word 'lanten'. Did you mean 'lantern'?"
+    );
+    assert_eq!(error.unknown_word(), Some("lanten"));
+    assert_eq!(error.suggestion(), Some("lantern"));
+    let error = ParserError::UnknownVerb {
+      word: "frob".to_string(),
+      suggestion: None,
+    };
+    assert_eq!(error.to_string(), "I don't know the verb 'frob'.");
+    assert_eq!(error.suggestion(), None);
+  }
 }
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+/// Verb synonyms and abbreviations understood by default, with the verbs
+/// they stand for.
+pub const DEFAULT_SYNONYMS: &[(&str, &str)] = &[
+  ("fight", "attack"),
+  ("g", "again"),
+  ("get", "take"),
+  ("grab", "take"),
+  ("hit", "attack"),
+  ("i", "inventory"),
+  ("inv", "inventory"),
+  ("kill", "attack"),
+  ("l", "look"),
+  ("q", "quit"),
+  ("shout", "yell"),
+  ("strike", "attack"),
+  ("x", "examine"),
+  ("z", "wait"),
+];
+
+/// The parser's vocabulary of verb synonyms and abbreviations.
+///
+/// Nouns and adjectives belong to the objects they refer to; see
+/// [`Object`](crate::world::prelude::Object).
+#[derive(Clone, Debug)]
+pub struct Lexicon {
+  /// Synonyms, keyed by the word the player types, with the verbs they
+  /// stand for.
+  synonyms: BTreeMap<String, String>,
+}
+
+impl Lexicon {
+  /// Create a new lexicon with the default synonyms.
+  pub fn new() -> Self {
+    let mut lexicon = Self::empty();
+    for (synonym, verb) in DEFAULT_SYNONYMS {
+      lexicon.add_synonym(synonym, verb);
+    }
+    lexicon
+  }
+
+  /// Create a new lexicon without any synonyms.
+  pub fn empty() -> Self {
+    Self {
+      synonyms: BTreeMap::new(),
+    }
+  }
+
+  /// Add a synonym for a verb, replacing any existing synonym.
+  pub fn add_synonym(&mut self, synonym: &str, verb: &str) {
+    self.synonyms.insert(synonym.to_lowercase(), verb.to_lowercase());
+  }
+
+  /// Look up the verb a word stands for, which is the word itself if it is
+  /// not a synonym.
+  pub fn verb<'a>(&'a self, word: &'a str) -> &'a str {
+    self.synonyms.get(word).map_or(word, String::as_str)
+  }
+}
+
+impl Default for Lexicon {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_default_synonyms() {
+    test_utils::init();
+    let lexicon = Lexicon::new();
+    assert_eq!(lexicon.verb("get"), "take");
+    assert_eq!(lexicon.verb("x"), "examine");
+    assert_eq!(lexicon.verb("l"), "look");
+    assert_eq!(lexicon.verb("i"), "inventory");
+    assert_eq!(lexicon.verb("z"), "wait");
+    assert_eq!(lexicon.verb("take"), "take");
+  }
+
+  #[test]
+  fn test_add_synonym() {
+    test_utils::init();
+    let mut lexicon = Lexicon::empty();
+    assert_eq!(lexicon.verb("get"), "get");
+    lexicon.add_synonym("Snatch", "take");
+    assert_eq!(lexicon.verb("snatch"), "take");
+  }
+}
@@ -1,55 +1,732 @@
 use crate::command::commands::*;
 use crate::command::prelude::Command;
 use crate::command::prelude::CommandError;
+use crate::command::registry::{CommandDefinition, CommandRegistry};
+use crate::game::prelude::{GameState, PendingQuestion};
+use crate::parser::action::{tokenize, Action, NounPhrase, CONJUNCTIONS, PRONOUNS, QUOTE};
 use crate::parser::error::ParserError;
+use crate::parser::lexicon::Lexicon;
+use crate::parser::spelling;
+use crate::world::prelude::{Direction, Location, ObjectId};
+use std::collections::BTreeMap;
+
+/// The verbs the parser understands without any registered commands, besides
+/// compass directions.
+const BUILT_IN_VERBS: &[&str] = &[
+  "again",
+  "alias",
+  "aliases",
+  "attack",
+  "close",
+  "debug:succeed",
+  "debug:throw_error:ic",
+  "debug:throw_error:oc",
+  "diagnose",
+  "drop",
+  "enter",
+  "examine",
+  "exit",
+  "extinguish",
+  "go",
+  "inventory",
+  "leave",
+  "light",
+  "lock",
+  "look",
+  "macro",
+  "open",
+  "put",
+  "quit",
+  "restore",
+  "save",
+  "say",
+  "switch",
+  "take",
+  "time",
+  "turn",
+  "unalias",
+  "undo",
+  "unlock",
+  "wait",
+  "write",
+  "yell",
+];
+
+/// Words that refer to all of the objects a verb could act upon.
+const ALL_WORDS: &[&str] = &["all", "everything"];
+
+/// Builds the command that acts upon a single object.
+type ObjectCommand = Box<dyn Fn(&str) -> Command>;
 
 /// Parser implementation.
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Parser;
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+  /// Commands registered in addition to the built-in commands.
+  registry: CommandRegistry,
+  /// Verb synonyms and abbreviations.
+  lexicon: Lexicon,
+  /// Whether unknown words are corrected automatically.
+  autocorrect: bool,
+  /// Commands to run when the player says or yells a phrase, keyed by the
+  /// phrase's words.
+  phrases: BTreeMap<String, Command>,
+}
 
 impl Parser {
   /// Create a new parser.
   pub fn new() -> Self {
-    Self {}
+    Self::default()
+  }
+
+  /// Register a command definition, so that its verbs are understood.
+  pub fn register(&mut self, definition: impl CommandDefinition + 'static) {
+    self.registry.register(definition);
+  }
+
+  /// Add a synonym for a verb (e.g. "grab" for "take").
+  pub fn add_synonym(&mut self, synonym: &str, verb: &str) {
+    self.lexicon.add_synonym(synonym, verb);
+  }
+
+  /// React to the player saying or yelling a phrase by running the command
+  /// instead.
+  ///
+  /// Phrases match regardless of case and punctuation, so "hello, sailor"
+  /// matches `yell "Hello, Sailor!"`.
+  pub fn add_phrase(&mut self, phrase: &str, command: Command) {
+    self.phrases.insert(phrase_words(phrase), command);
   }
 
-  /// Parse a string into a command.
-  pub fn parse(&self, input: &str) -> Result<Command, ParserError> {
+  /// Parse a string into a single command.
+  ///
+  /// This is [`Parser::parse_commands`] for input that acts upon at most one
+  /// object.
+  pub fn parse(&self, input: &str, game_state: &GameState) -> Result<Command, ParserError> {
+    let mut commands = self.parse_commands(input, game_state)?;
+    match commands.len() {
+      1 => Ok(commands.remove(0)),
+      _ => Err(ParserError::TooManyObjects(self.action(input.trim())?.verb)),
+    }
+  }
+
+  /// Parse a string into commands.
+  ///
+  /// The input is first parsed into an [`Action`], and any objects the player
+  /// mentions are then resolved against the objects in scope in the game
+  /// state. Input that acts upon several objects (e.g. "take all" or "drop
+  /// lamp and sword") produces a labelled command for each object.
+  pub fn parse_commands(&self, input: &str, game_state: &GameState) -> Result<Vec<Command>, ParserError> {
     let input = input.trim();
-    let parts: Vec<&str> = input.split_whitespace().collect();
-    match parts.as_slice() {
-      //["go", direction] => Ok(Command::Go(direction.to_string())),
-      //["look"] => Ok(Command::Look),
-      ["quit"] => Ok(Command::Quit(QuitCommand)),
-      ["debug:succeed"] => Ok(Command::Succeed(SucceedCommand)),
-      ["debug:throw_error:ic"] => Ok(Command::ThrowError(ThrowErrorCommand {
+    let action = self.action(input)?;
+    self.parse_action(input, &action, game_state)
+  }
+
+  /// Whether unknown words are corrected automatically, rather than just
+  /// suggesting a correction.
+  pub fn autocorrects(&self) -> bool {
+    self.autocorrect
+  }
+
+  /// Set whether unknown words are corrected automatically.
+  pub fn set_autocorrect(&mut self, autocorrect: bool) {
+    self.autocorrect = autocorrect;
+  }
+
+  /// Apply `oops <word>` to the last input that failed because of an unknown
+  /// word, giving the corrected input to parse instead.
+  ///
+  /// Returns `None` if the input isn't "oops".
+  pub fn apply_oops(&self, input: &str, game_state: &GameState) -> Option<Result<String, ParserError>> {
+    let words = tokenize(input);
+    if words.first().map(String::as_str) != Some("oops") {
+      return None;
+    }
+    let result = match (words.as_slice(), &game_state.failed_input) {
+      (_, None) => Err(ParserError::NothingToCorrect),
+      ([_, word], Some(failed)) => Ok(spelling::replace_word(&failed.input, &failed.word, word)),
+      ([_], Some(_)) => Err(ParserError::MissingDirectObject("oops".to_string())),
+      _ => Err(ParserError::TooManyObjects("oops".to_string())),
+    };
+    Some(result)
+  }
+
+  /// The verbs the parser understands, for suggesting corrections.
+  fn verbs(&self) -> Vec<String> {
+    BUILT_IN_VERBS
+      .iter()
+      .filter(|verb| !verb.starts_with("debug:"))
+      .map(|verb| verb.to_string())
+      .chain(Direction::ALL.iter().map(|direction| direction.name().to_string()))
+      .chain(self.registry.verbs())
+      .collect()
+  }
+
+  /// The nouns and adjectives of the objects in scope, for suggesting
+  /// corrections.
+  fn nouns(&self, game_state: &GameState) -> Vec<String> {
+    let world = &game_state.world;
+    let mut nouns = Vec::new();
+    for object in world.objects_in_scope().iter().filter_map(|id| world.object(id)) {
+      nouns.extend(object.name.split_whitespace().map(str::to_string));
+      nouns.extend(object.nouns.iter().cloned());
+      nouns.extend(object.adjectives.iter().cloned());
+    }
+    nouns
+  }
+
+  /// Parse the player's answer to a question about which object they meant.
+  ///
+  /// The answer (e.g. "the brass one") is combined with the noun phrase from
+  /// the original input (e.g. "take key") and the result is parsed. Returns
+  /// `None` if the answer doesn't narrow down the objects, in which case it
+  /// should be treated as a new command.
+  pub fn parse_answer(
+    &self,
+    question: &PendingQuestion,
+    answer: &str,
+    game_state: &GameState,
+  ) -> Option<Result<Vec<Command>, ParserError>> {
+    let mut words: Vec<String> = tokenize(answer)
+      .into_iter()
+      .filter(|word| !matches!(word.as_str(), "one" | "ones"))
+      .collect();
+    if words.is_empty() {
+      return None;
+    }
+    let noun_phrase = &question.noun_phrase;
+    // Keep the original noun unless the answer names an object outright.
+    let answer_names_object = words
+      .last()
+      .is_some_and(|word| question.candidates.iter().any(|id| is_noun_of(game_state, id, word)));
+    if !answer_names_object {
+      words.extend(noun_phrase.words.iter().cloned());
+    }
+    let candidates: Vec<ObjectId> = game_state
+      .world
+      .find_objects(&words.iter().map(String::as_str).collect::<Vec<_>>())
+      .into_iter()
+      .filter(|id| question.candidates.contains(id))
+      .collect();
+    if candidates.is_empty() {
+      return None;
+    }
+    let mut action = self.action(&question.input).ok()?;
+    let merged = NounPhrase { words };
+    for object in [&mut action.direct_object, &mut action.indirect_object] {
+      *object = object.as_ref().map(|object| object.replace(noun_phrase, &merged));
+    }
+    Some(self.parse_action(&question.input, &action, game_state))
+  }
+
+  /// Parse the input into an action, with any verb synonyms replaced.
+  ///
+  /// A registered verb is never treated as a synonym, so that a game can
+  /// register a verb (e.g. "x") that the lexicon would otherwise replace.
+  fn action(&self, input: &str) -> Result<Action, ParserError> {
+    let mut action = Action::parse(input).ok_or_else(|| ParserError::InvalidInput(input.to_string()))?;
+    if self.registry.get(&action.verb).is_none() {
+      action.verb = self.lexicon.verb(&action.verb).to_string();
+    }
+    Ok(action)
+  }
+
+  /// Convert an action into commands, using the registered commands before
+  /// the built-in commands.
+  fn parse_action(&self, input: &str, action: &Action, game_state: &GameState) -> Result<Vec<Command>, ParserError> {
+    if let Some(definition) = self.registry.get(&action.verb) {
+      return Ok(vec![definition.parse(action, game_state)?]);
+    }
+    if let Some(commands) = self.commands(input, action, game_state)? {
+      return Ok(commands);
+    }
+    Ok(vec![self.command(input, action, game_state)?])
+  }
+
+  /// Convert an action that can act upon several objects at once into a
+  /// command for each object, or `None` if the action can't.
+  fn commands(
+    &self,
+    input: &str,
+    action: &Action,
+    game_state: &GameState,
+  ) -> Result<Option<Vec<Command>>, ParserError> {
+    let world = &game_state.world;
+    let verb = action.verb.as_str();
+    if !matches!(verb, "take" | "drop" | "examine" | "put") {
+      return Ok(None);
+    }
+    let noun_phrase = action
+      .direct_object
+      .as_ref()
+      .ok_or_else(|| ParserError::MissingDirectObject(verb.to_string()))?;
+    let preposition = action.preposition.as_deref();
+    // What "all" means depends on the verb.
+    let (all, command): (Vec<ObjectId>, ObjectCommand) = match (verb, preposition) {
+      ("take", None) => {
+        let mut all = if world.is_lit() {
+          world.contents(&Location::Room(world.player_location.clone()))
+        } else {
+          Vec::new()
+        };
+        all.retain(|id| world.object(id).is_some_and(|object| !object.fixed));
+        (all, Box::new(|id| Command::Take(TakeCommand::new(id))))
+      },
+      ("drop", None) => (world.inventory(), Box::new(|id| Command::Drop(DropCommand::new(id)))),
+      ("examine", None) => (
+        world.objects_in_scope(),
+        Box::new(|id| Command::Examine(ExamineCommand::new(id))),
+      ),
+      ("put", Some(preposition @ ("in" | "into" | "inside" | "on" | "onto"))) => {
+        let target = match &action.indirect_object {
+          Some(noun_phrase) => self.resolve(input, action, noun_phrase, game_state)?,
+          None => {
+            return Err(ParserError::MissingIndirectObject {
+              verb: verb.to_string(),
+              preposition: preposition.to_string(),
+            })
+          },
+        };
+        let mut all = world.inventory();
+        all.retain(|id| *id != target);
+        let on = matches!(preposition, "on" | "onto");
+        (
+          all,
+          Box::new(move |id| {
+            if on {
+              Command::Put(PutCommand::new_on(id, &target))
+            } else {
+              Command::Put(PutCommand::new_in(id, &target))
+            }
+          }),
+        )
+      },
+      ("put", None) => {
+        return Err(ParserError::MissingIndirectObject {
+          verb: verb.to_string(),
+          preposition: "in".to_string(),
+        })
+      },
+      (_, Some(preposition)) => {
+        return Err(ParserError::BadPreposition {
+          verb: verb.to_string(),
+          preposition: preposition.to_string(),
+        })
+      },
+      _ => return Ok(None),
+    };
+    let (objects, labelled) = self.resolve_many(input, action, noun_phrase, &all, game_state)?;
+    let commands = objects
+      .iter()
+      .map(|id| match (labelled, world.object(id)) {
+        (true, Some(object)) => Command::Labelled(LabelledCommand::new(&object.name, command(id))),
+        _ => command(id),
+      })
+      .collect();
+    Ok(Some(commands))
+  }
+
+  /// Convert an action into a command.
+  fn command(&self, input: &str, action: &Action, game_state: &GameState) -> Result<Command, ParserError> {
+    let invalid = || ParserError::InvalidInput(input.to_string());
+    let preposition = action.preposition.as_deref();
+    let indirect_object = || match &action.indirect_object {
+      Some(noun_phrase) => self.resolve(input, action, noun_phrase, game_state),
+      None => Err(ParserError::MissingIndirectObject {
+        verb: action.verb.clone(),
+        preposition: preposition.unwrap_or_default().to_string(),
+      }),
+    };
+    match action.verb.as_str() {
+      verb if action.is_bare() && Direction::from_word(verb).is_some() => Ok(Command::Go(GoCommand::new(verb))),
+      "go" | "enter" => self.parse_go(input, action),
+      "exit" | "leave" if action.is_bare() => Ok(Command::Go(GoCommand::new("out"))),
+      "look" if action.is_bare() => Ok(Command::Look(LookCommand)),
+      "look" if action.direct_object.is_none() && matches!(preposition, Some("in" | "inside" | "into")) => {
+        Ok(Command::LookIn(LookInCommand::new(&indirect_object()?)))
+      },
+      "look" if action.direct_object.is_none() && preposition == Some("at") => {
+        Ok(Command::Examine(ExamineCommand::new(&indirect_object()?)))
+      },
+      "look" if action.direct_object.is_none() => Err(ParserError::BadPreposition {
+        verb: action.verb.clone(),
+        preposition: preposition.unwrap_or_default().to_string(),
+      }),
+      "inventory" if action.is_bare() => Ok(Command::Inventory(InventoryCommand)),
+      "save" if action.preposition.is_none() => Ok(Command::Save(SaveCommand::new(&self.name(input, action)?))),
+      "restore" if action.preposition.is_none() => {
+        Ok(Command::Restore(RestoreCommand::new(&self.name(input, action)?)))
+      },
+      "undo" if action.is_bare() => Ok(Command::Undo(UndoCommand::new(1))),
+      "undo" if action.preposition.is_none() => match self.name(input, action)?.parse() {
+        Ok(levels) => Ok(Command::Undo(UndoCommand::new(levels))),
+        Err(_) => Err(invalid()),
+      },
+      "say" | "yell" => {
+        let text = match &action.text {
+          Some(text) => text.as_str(),
+          // Without quotes, everything after the verb is what to say.
+          None => match input.split_once(char::is_whitespace) {
+            Some((_, text)) => text.trim(),
+            None => return Err(ParserError::MissingDirectObject(action.verb.clone())),
+          },
+        };
+        if let Some(command) = self.phrases.get(&phrase_words(text)) {
+          return Ok(command.clone());
+        }
+        match action.verb.as_str() {
+          "yell" => Ok(Command::Say(SayCommand::new_yell(text))),
+          _ => Ok(Command::Say(SayCommand::new(text))),
+        }
+      },
+      "write" => {
+        let text = match (&action.text, &action.direct_object) {
+          (Some(text), None) => text.clone(),
+          (None, Some(noun_phrase)) => noun_phrase.to_string(),
+          (Some(_), Some(_)) => return Err(invalid()),
+          (None, None) => return Err(ParserError::MissingDirectObject(action.verb.clone())),
+        };
+        match preposition {
+          Some("on" | "onto" | "in") => Ok(Command::Write(WriteCommand::new(&text, &indirect_object()?))),
+          None => Err(ParserError::MissingIndirectObject {
+            verb: action.verb.clone(),
+            preposition: "on".to_string(),
+          }),
+          Some(preposition) => Err(ParserError::BadPreposition {
+            verb: action.verb.clone(),
+            preposition: preposition.to_string(),
+          }),
+        }
+      },
+      "turn" | "switch" => {
+        // Either "turn on the lamp" or "turn the lamp on".
+        let object = match (&action.direct_object, &action.indirect_object) {
+          (Some(object), None) | (None, Some(object)) => self.resolve(input, action, object, game_state)?,
+          _ => return Err(invalid()),
+        };
+        match preposition {
+          Some("on") => Ok(Command::Switch(SwitchCommand::new_on(&object))),
+          Some("off") => Ok(Command::Switch(SwitchCommand::new_off(&object))),
+          _ => Err(invalid()),
+        }
+      },
+      "light" | "extinguish" if action.preposition.is_none() => {
+        let object = match &action.direct_object {
+          Some(object) => self.resolve(input, action, object, game_state)?,
+          None => return Err(ParserError::MissingDirectObject(action.verb.clone())),
+        };
+        match action.verb.as_str() {
+          "light" => Ok(Command::Switch(SwitchCommand::new_on(&object))),
+          _ => Ok(Command::Switch(SwitchCommand::new_off(&object))),
+        }
+      },
+      "open" | "close" if action.preposition.is_none() => {
+        let object = match &action.direct_object {
+          Some(object) => self.resolve(input, action, object, game_state)?,
+          None => return Err(ParserError::MissingDirectObject(action.verb.clone())),
+        };
+        match action.verb.as_str() {
+          "open" => Ok(Command::Open(OpenCommand::new(&object))),
+          _ => Ok(Command::Close(CloseCommand::new(&object))),
+        }
+      },
+      "lock" | "unlock" if matches!(preposition, None | Some("with")) => {
+        let object = match &action.direct_object {
+          Some(object) => self.resolve(input, action, object, game_state)?,
+          None => return Err(ParserError::MissingDirectObject(action.verb.clone())),
+        };
+        let key = match &action.indirect_object {
+          Some(key) => self.resolve(input, action, key, game_state)?,
+          None => {
+            return Err(ParserError::MissingIndirectObject {
+              verb: action.verb.clone(),
+              preposition: "with".to_string(),
+            })
+          },
+        };
+        match action.verb.as_str() {
+          "lock" => Ok(Command::Lock(LockCommand::new(&object, &key))),
+          _ => Ok(Command::Unlock(UnlockCommand::new(&object, &key))),
+        }
+      },
+      "attack" if matches!(preposition, None | Some("with")) => {
+        // The target is normally a character, but may be an object.
+        let target = match &action.direct_object {
+          Some(noun_phrase) => match game_state.find_npc(&noun_phrase.words()) {
+            Some(npc) => AttackTarget::Npc(npc),
+            None if game_state.npcs.values().any(|npc| npc.is_called(&noun_phrase.words())) => {
+              return Err(ParserError::NotInScope(noun_phrase.clone()))
+            },
+            None => AttackTarget::Object(self.resolve(input, action, noun_phrase, game_state)?),
+          },
+          None => return Err(ParserError::MissingDirectObject(action.verb.clone())),
+        };
+        let weapon = match preposition {
+          Some(_) => Some(indirect_object()?),
+          None => None,
+        };
+        Ok(Command::Attack(AttackCommand::new(target, weapon.as_deref())))
+      },
+      "diagnose" if action.is_bare() => Ok(Command::Diagnose(DiagnoseCommand)),
+      "again" if action.is_bare() => Ok(Command::Again(AgainCommand)),
+      "alias" | "aliases" if action.is_bare() => Ok(Command::Aliases(AliasesCommand)),
+      "alias" | "macro" => self.parse_alias(input, action),
+      "unalias" if action.preposition.is_none() => Ok(Command::Alias(AliasCommand::remove(&self.name(input, action)?))),
+      "wait" if action.is_bare() => Ok(Command::Wait(WaitCommand)),
+      "time" if action.is_bare() => Ok(Command::Time(TimeCommand)),
+      "quit" if action.is_bare() => Ok(Command::Quit(QuitCommand)),
+      "debug:succeed" if action.is_bare() => Ok(Command::Succeed(SucceedCommand)),
+      "debug:throw_error:ic" if action.is_bare() => Ok(Command::ThrowError(ThrowErrorCommand {
         error: CommandError::InCharacter("Test error.".to_string()),
       })),
-      ["debug:throw_error:oc"] => Ok(Command::ThrowError(ThrowErrorCommand {
+      "debug:throw_error:oc" if action.is_bare() => Ok(Command::ThrowError(ThrowErrorCommand {
         error: CommandError::OutOfCharacter("Test error.".to_string()),
       })),
-      _ => Err(ParserError::InvalidInput(input.to_string())),
+      verb if !BUILT_IN_VERBS.contains(&verb) && Direction::from_word(verb).is_none() => {
+        Err(ParserError::UnknownVerb {
+          word: verb.to_string(),
+          suggestion: spelling::suggest(verb, self.verbs().iter().map(String::as_str)).map(str::to_string),
+        })
+      },
+      _ => Err(invalid()),
     }
   }
+
+  /// Convert a "go" or "enter" action (e.g. "go north", "enter the white
+  /// house", or "go into house") into a command.
+  fn parse_go(&self, input: &str, action: &Action) -> Result<Command, ParserError> {
+    let exit = match (
+      &action.direct_object,
+      action.preposition.as_deref(),
+      &action.indirect_object,
+    ) {
+      (Some(exit), None, None) => exit.to_string(),
+      (None, Some("in" | "inside" | "into" | "through" | "to"), Some(exit)) => exit.to_string(),
+      (None, Some(preposition), None) => preposition.to_string(),
+      (None, None, None) if action.verb == "enter" => "in".to_string(),
+      _ => return Err(ParserError::InvalidInput(input.to_string())),
+    };
+    Ok(Command::Go(GoCommand::new(&exit)))
+  }
+
+  /// Convert an "alias" or "macro" action (e.g. "alias kk = kill kobold with
+  /// sword" or `macro dig = "take shovel; dig; drop shovel"`) into a command.
+  ///
+  /// The definition is taken from the input as typed, so that it keeps its
+  /// case and punctuation. A macro's body must be quoted, so that the input
+  /// reader leaves it whole; it is kept with its separators and split into
+  /// inputs when the macro is used. An alias's body is not quoted, so an
+  /// alias always stands for a single input: "alias x = look; n" defines "x"
+  /// as "look" and then goes north.
+  fn parse_alias(&self, input: &str, action: &Action) -> Result<Command, ParserError> {
+    let invalid = || ParserError::InvalidInput(input.to_string());
+    let definition = input
+      .split_once(char::is_whitespace)
+      .map_or("", |(_, definition)| definition);
+    let (name, expansion) = definition.split_once('=').ok_or_else(invalid)?;
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+      return Err(invalid());
+    }
+    let expansion = match action.verb.as_str() {
+      "macro" => expansion
+        .trim()
+        .strip_prefix(QUOTE)
+        .and_then(|body| body.strip_suffix(QUOTE))
+        .ok_or_else(invalid)?,
+      _ => expansion,
+    };
+    let expansion = expansion.trim();
+    if expansion.is_empty() {
+      return Err(invalid());
+    }
+    Ok(Command::Alias(AliasCommand::new(name, &[expansion])))
+  }
+
+  /// The single word following the verb (e.g. the name of a saved game).
+  fn name(&self, input: &str, action: &Action) -> Result<String, ParserError> {
+    match &action.direct_object {
+      Some(noun_phrase) if noun_phrase.words.len() == 1 => Ok(noun_phrase.words[0].clone()),
+      Some(_) => Err(ParserError::InvalidInput(input.to_string())),
+      None => Err(ParserError::MissingDirectObject(action.verb.clone())),
+    }
+  }
+
+  /// Resolve a noun phrase to a single object in scope.
+  fn resolve(
+    &self,
+    input: &str,
+    action: &Action,
+    noun_phrase: &NounPhrase,
+    game_state: &GameState,
+  ) -> Result<ObjectId, ParserError> {
+    let words = noun_phrase.words();
+    if words
+      .iter()
+      .any(|word| CONJUNCTIONS.contains(word) || ALL_WORDS.contains(word))
+    {
+      return Err(ParserError::TooManyObjects(action.verb.clone()));
+    }
+    let mut objects = self.resolve_part(input, &words, game_state)?;
+    Ok(objects.remove(0))
+  }
+
+  /// Resolve a compound noun phrase (e.g. "lamp, key and rope" or "all but
+  /// the sword") to objects in scope, where `all` is what "all" means.
+  ///
+  /// Also returns whether the objects should be labelled, which they should
+  /// if the player mentioned more than one object or said "all".
+  fn resolve_many(
+    &self,
+    input: &str,
+    action: &Action,
+    noun_phrase: &NounPhrase,
+    all: &[ObjectId],
+    game_state: &GameState,
+  ) -> Result<(Vec<ObjectId>, bool), ParserError> {
+    let words = noun_phrase.words();
+    let (included, excluded) = match words.iter().position(|word| matches!(*word, "but" | "except")) {
+      Some(position) => (&words[..position], &words[position + 1..]),
+      None => (&words[..], &[][..]),
+    };
+    let mut objects: Vec<ObjectId> = Vec::new();
+    let mut labelled = false;
+    for part in included.split(|word| *word == "and") {
+      if let [word] = part {
+        if ALL_WORDS.contains(word) {
+          objects.extend(all.iter().cloned());
+          labelled = true;
+          continue;
+        }
+      }
+      objects.extend(self.resolve_part(input, part, game_state)?);
+    }
+    if !excluded.is_empty() {
+      for part in excluded.split(|word| *word == "and") {
+        let exceptions = self.resolve_part(input, part, game_state)?;
+        objects.retain(|id| !exceptions.contains(id));
+      }
+    }
+    let mut unique = Vec::new();
+    for id in objects {
+      if !unique.contains(&id) {
+        unique.push(id);
+      }
+    }
+    if unique.is_empty() {
+      return Err(ParserError::NoObjects(action.verb.clone()));
+    }
+    labelled |= unique.len() > 1;
+    Ok((unique, labelled))
+  }
+
+  /// Resolve part of a noun phrase (e.g. "brass lantern" or "them") to one or
+  /// more objects in scope.
+  ///
+  /// A pronoun refers to the objects the player last referred to that are
+  /// still in scope; "it" refers to just the first of them.
+  fn resolve_part(&self, input: &str, words: &[&str], game_state: &GameState) -> Result<Vec<ObjectId>, ParserError> {
+    if let [pronoun] = words {
+      if PRONOUNS.contains(pronoun) {
+        let scope = game_state.world.objects_in_scope();
+        let mut objects: Vec<ObjectId> = game_state
+          .last_objects
+          .iter()
+          .filter(|id| scope.contains(id))
+          .cloned()
+          .collect();
+        if *pronoun == "it" {
+          objects.truncate(1);
+        }
+        if objects.is_empty() {
+          return Err(ParserError::UnresolvedPronoun(pronoun.to_string()));
+        }
+        return Ok(objects);
+      }
+    }
+    if words.is_empty() {
+      return Err(ParserError::InvalidInput(input.to_string()));
+    }
+    let world = &game_state.world;
+    let candidates = world.find_objects(words);
+    match candidates.len() {
+      0 => match words.iter().find(|word| !world.knows_word(word)) {
+        Some(word) => Err(ParserError::UnknownNoun {
+          word: word.to_string(),
+          suggestion: spelling::suggest(word, self.nouns(game_state).iter().map(String::as_str)).map(str::to_string),
+        }),
+        None => Err(ParserError::NotInScope(NounPhrase::new(words))),
+      },
+      1 => Ok(candidates),
+      _ => Err(ParserError::AmbiguousNounPhrase {
+        input: input.to_string(),
+        noun_phrase: NounPhrase::new(words),
+        names: candidates
+          .iter()
+          .filter_map(|id| world.object(id))
+          .map(|object| object.definite_name())
+          .collect(),
+        candidates,
+      }),
+    }
+  }
+}
+
+/// Whether the word is a noun that refers to the object.
+fn is_noun_of(game_state: &GameState, id: &str, word: &str) -> bool {
+  game_state.world.object(id).is_some_and(|object| object.has_noun(word))
+}
+
+/// The words of a phrase, in lowercase and without punctuation, so that
+/// phrases can be compared.
+fn phrase_words(phrase: &str) -> String {
+  phrase
+    .to_lowercase()
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ")
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::command::prelude::{Command, QuitCommand};
+  use crate::command::registry::tests::{Dig, DigCommand};
+  use crate::game::prelude::{FailedInput, Npc};
+  use crate::test_utils;
+  use crate::world::prelude::Object;
+  use std::sync::Arc;
 
   #[test]
   fn test_parse() {
     let parser = Parser::new();
-    let command = parser.parse("quit");
+    let game_state = GameState::default();
+    let command = parser.parse("quit", &game_state);
     assert!(command.is_ok());
     assert_eq!(command.unwrap(), Command::Quit(QuitCommand));
   }
 
+  #[test]
+  fn test_parse_case_and_extra_words() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert_eq!(parser.parse("QUIT", &game_state).unwrap(), Command::Quit(QuitCommand));
+    assert_eq!(
+      parser.parse("quit now", &game_state).unwrap(),
+      Command::Quit(QuitCommand)
+    );
+    assert_eq!(
+      parser.parse("Take the Brass Lantern!", &game_state).unwrap(),
+      Command::Take(TakeCommand::new("lantern"))
+    );
+    assert!(parser.parse("quit the game", &game_state).is_err());
+  }
+
   #[test]
   fn test_parse_succeed() {
     let parser = Parser::new();
-    let command = parser.parse("debug:succeed");
+    let game_state = GameState::default();
+    let command = parser.parse("debug:succeed", &game_state);
     assert!(command.is_ok());
     assert_eq!(command.unwrap(), Command::Succeed(SucceedCommand));
   }
@@ -57,7 +734,8 @@ mod tests {
   #[test]
   fn test_parse_throw_error_ic() {
     let parser = Parser::new();
-    let command = parser.parse("debug:throw_error:ic");
+    let game_state = GameState::default();
+    let command = parser.parse("debug:throw_error:ic", &game_state);
     assert!(command.is_ok());
     assert_eq!(
       command.unwrap(),
@@ -70,7 +748,8 @@ mod tests {
   #[test]
   fn test_parse_throw_error_oc() {
     let parser = Parser::new();
-    let command = parser.parse("debug:throw_error:oc");
+    let game_state = GameState::default();
+    let command = parser.parse("debug:throw_error:oc", &game_state);
     assert!(command.is_ok());
     assert_eq!(
       command.unwrap(),
@@ -80,10 +759,675 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_go() {
+    let parser = Parser::new();
+    let game_state = GameState::default();
+    for input in ["go north", "north", "n", "go n"] {
+      assert_eq!(
+        parser.parse(input, &game_state).unwrap(),
+        Command::Go(GoCommand::new("north"))
+      );
+    }
+    for (input, exit) in [
+      ("s", "south"),
+      ("e", "east"),
+      ("w", "west"),
+      ("ne", "northeast"),
+      ("up", "up"),
+      ("down", "down"),
+      ("in", "in"),
+      ("out", "out"),
+      ("enter", "in"),
+      ("exit", "out"),
+    ] {
+      assert_eq!(
+        parser.parse(input, &game_state).unwrap(),
+        Command::Go(GoCommand::new(exit))
+      );
+    }
+  }
+
+  #[test]
+  fn test_parse_go_named_exit() {
+    let parser = Parser::new();
+    let game_state = GameState::default();
+    assert_eq!(
+      parser.parse("enter house", &game_state).unwrap(),
+      Command::Go(GoCommand::new("house"))
+    );
+    assert_eq!(
+      parser.parse("go house", &game_state).unwrap(),
+      Command::Go(GoCommand::new("house"))
+    );
+    assert_eq!(
+      parser.parse("enter white house", &game_state).unwrap(),
+      Command::Go(GoCommand::new("white house"))
+    );
+    assert_eq!(
+      parser.parse("go into the house", &game_state).unwrap(),
+      Command::Go(GoCommand::new("house"))
+    );
+    assert_eq!(
+      parser.parse("go in", &game_state).unwrap(),
+      Command::Go(GoCommand::new("in"))
+    );
+    assert!(parser.parse("go", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_look() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert_eq!(parser.parse("look", &game_state).unwrap(), Command::Look(LookCommand));
+    assert_eq!(
+      parser.parse("look in mailbox", &game_state).unwrap(),
+      Command::LookIn(LookInCommand::new("mailbox"))
+    );
+    assert!(parser.parse("look in sword", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_take_and_drop() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert_eq!(
+      parser.parse("take leaflet", &game_state).unwrap(),
+      Command::Take(TakeCommand::new("leaflet"))
+    );
+    assert_eq!(
+      parser.parse("drop brass lantern", &game_state).unwrap(),
+      Command::Drop(DropCommand::new("lantern"))
+    );
+    assert!(parser.parse("take", &game_state).is_err());
+    assert!(parser.parse("take sword", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_put() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert_eq!(
+      parser.parse("put lantern in small mailbox", &game_state).unwrap(),
+      Command::Put(PutCommand::new_in("lantern", "mailbox"))
+    );
+    assert_eq!(
+      parser.parse("put brass lantern on table", &game_state).unwrap(),
+      Command::Put(PutCommand::new_on("lantern", "table"))
+    );
+    assert_eq!(
+      parser
+        .parse("put the brass lantern into the wooden box", &game_state)
+        .unwrap(),
+      Command::Put(PutCommand::new_in("lantern", "box"))
+    );
+    assert!(parser.parse("put lantern with box", &game_state).is_err());
+    assert!(parser.parse("put lantern", &game_state).is_err());
+    assert!(parser.parse("put in mailbox", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_inventory() {
+    let parser = Parser::new();
+    let game_state = GameState::default();
+    assert_eq!(
+      parser.parse("inventory", &game_state).unwrap(),
+      Command::Inventory(InventoryCommand)
+    );
+  }
+
+  #[test]
+  fn test_parse_save_and_restore() {
+    let parser = Parser::new();
+    let game_state = GameState::default();
+    assert_eq!(
+      parser.parse("save game", &game_state).unwrap(),
+      Command::Save(SaveCommand::new("game"))
+    );
+    assert_eq!(
+      parser.parse("restore game", &game_state).unwrap(),
+      Command::Restore(RestoreCommand::new("game"))
+    );
+    assert!(parser.parse("save", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_undo() {
+    let parser = Parser::new();
+    let game_state = GameState::default();
+    assert_eq!(
+      parser.parse("undo", &game_state).unwrap(),
+      Command::Undo(UndoCommand::new(1))
+    );
+    assert_eq!(
+      parser.parse("undo 3", &game_state).unwrap(),
+      Command::Undo(UndoCommand::new(3))
+    );
+    assert!(parser.parse("undo everything", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_synonyms() {
+    let mut parser = Parser::new();
+    let game_state = test_utils::test_state();
+    for (input, command) in [
+      ("get leaflet", Command::Take(TakeCommand::new("leaflet"))),
+      ("x lamp", Command::Examine(ExamineCommand::new("lantern"))),
+      ("look at the light", Command::Examine(ExamineCommand::new("lantern"))),
+      (
+        "examine brass lantern",
+        Command::Examine(ExamineCommand::new("lantern")),
+      ),
+      ("l", Command::Look(LookCommand)),
+      ("I", Command::Inventory(InventoryCommand)),
+      ("z", Command::Wait(WaitCommand)),
+    ] {
+      assert_eq!(parser.parse(input, &game_state).unwrap(), command);
+    }
+    assert!(parser.parse("snatch leaflet", &game_state).is_err());
+    parser.add_synonym("snatch", "take");
+    assert_eq!(
+      parser.parse("snatch leaflet", &game_state).unwrap(),
+      Command::Take(TakeCommand::new("leaflet"))
+    );
+  }
+
+  /// A game state with two keys in scope.
+  fn keys_state() -> GameState {
+    let mut game_state = test_utils::test_state();
+    let world = &mut game_state.world;
+    world.add_object("brass_key", Object::new("brass key", "").at(Location::Player));
+    world.add_object("iron_key", Object::new("iron key", "").at(Location::Player));
+    game_state
+  }
+
+  #[test]
+  fn test_parse_ambiguous() {
+    let parser = Parser::new();
+    let game_state = keys_state();
+    match parser.parse("drop the key", &game_state) {
+      Err(ParserError::AmbiguousNounPhrase {
+        input,
+        noun_phrase,
+        candidates,
+        names,
+      }) => {
+        assert_eq!(input, "drop the key");
+        assert_eq!(noun_phrase, NounPhrase::new(&["key"]));
+        assert_eq!(candidates, vec!["brass_key", "iron_key"]);
+        assert_eq!(names, vec!["the brass key", "the iron key"]);
+      },
+      other => panic!("Unexpected result: {:?}", other),
+    }
+    assert_eq!(
+      parser.parse("drop iron key", &game_state).unwrap(),
+      Command::Drop(DropCommand::new("iron_key"))
+    );
+  }
+
+  #[test]
+  fn test_parse_answer() {
+    let parser = Parser::new();
+    let game_state = keys_state();
+    let question = PendingQuestion {
+      input: "put key in mailbox".to_string(),
+      noun_phrase: NounPhrase::new(&["key"]),
+      candidates: vec!["brass_key".to_string(), "iron_key".to_string()],
+    };
+    let expected = Command::Put(PutCommand::new_in("iron_key", "mailbox"));
+    for answer in ["iron", "the iron one", "Iron key"] {
+      assert_eq!(
+        parser.parse_answer(&question, answer, &game_state).unwrap().unwrap(),
+        vec![expected.clone()]
+      );
+    }
+    assert!(parser.parse_answer(&question, "north", &game_state).is_none());
+    assert!(parser.parse_answer(&question, "the", &game_state).is_none());
+  }
+
+  #[test]
+  fn test_parse_pronouns() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    assert!(parser.parse("drop it", &game_state).is_err());
+    game_state.last_objects = vec!["lantern".to_string()];
+    assert_eq!(
+      parser.parse("drop it", &game_state).unwrap(),
+      Command::Drop(DropCommand::new("lantern"))
+    );
+    assert_eq!(
+      parser.parse("put it in the mailbox", &game_state).unwrap(),
+      Command::Put(PutCommand::new_in("lantern", "mailbox"))
+    );
+    game_state.last_objects = vec!["sword".to_string()];
+    assert!(parser.parse("take it", &game_state).is_err());
+  }
+
+  #[test]
+  fn test_parse_again() {
+    let parser = Parser::new();
+    let game_state = GameState::default();
+    for input in ["again", "g"] {
+      assert_eq!(parser.parse(input, &game_state).unwrap(), Command::Again(AgainCommand));
+    }
+  }
+
+  #[test]
+  fn test_parse_all() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    game_state.world.add_object(
+      "rope",
+      Object::new("rope", "").at(Location::Room("west_of_house".to_string())),
+    );
+    let labelled = |label: &str, command| Command::Labelled(LabelledCommand::new(label, command));
+    assert_eq!(
+      parser.parse_commands("take all", &game_state).unwrap(),
+      vec![
+        labelled("wooden box", Command::Take(TakeCommand::new("box"))),
+        labelled("rope", Command::Take(TakeCommand::new("rope"))),
+      ]
+    );
+    assert_eq!(
+      parser
+        .parse_commands("take everything except the box", &game_state)
+        .unwrap(),
+      vec![labelled("rope", Command::Take(TakeCommand::new("rope")))]
+    );
+    assert_eq!(
+      parser.parse_commands("drop all", &game_state).unwrap(),
+      vec![labelled("brass lantern", Command::Drop(DropCommand::new("lantern")))]
+    );
+    assert!(parser.parse_commands("drop all but the lamp", &game_state).is_err());
+    let examine_all = parser.parse_commands("examine all", &game_state).unwrap();
+    assert_eq!(examine_all.len(), game_state.world.objects_in_scope().len());
+    assert!(examine_all.contains(&labelled("rope", Command::Examine(ExamineCommand::new("rope")))));
+  }
+
+  #[test]
+  fn test_parse_multiple_objects() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    let labelled = |label: &str, command| Command::Labelled(LabelledCommand::new(label, command));
+    assert_eq!(
+      parser
+        .parse_commands("put lantern, leaflet and lantern in the box", &game_state)
+        .unwrap(),
+      vec![
+        labelled("brass lantern", Command::Put(PutCommand::new_in("lantern", "box"))),
+        labelled("leaflet", Command::Put(PutCommand::new_in("leaflet", "box"))),
+      ]
+    );
+    assert!(parser.parse("take leaflet and lantern", &game_state).is_err());
+    game_state.last_objects = vec!["leaflet".to_string(), "lantern".to_string()];
+    assert_eq!(parser.parse_commands("examine them", &game_state).unwrap().len(), 2);
+    assert_eq!(
+      parser.parse_commands("examine it", &game_state).unwrap(),
+      vec![Command::Examine(ExamineCommand::new("leaflet"))]
+    );
+  }
+
+  #[test]
+  fn test_parse_registered() {
+    let mut parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert!(parser.parse("dig", &game_state).is_err());
+    parser.register(Dig);
+    let command = parser.parse("Excavate with the spade", &game_state).unwrap();
+    let expected = Command::Custom(Arc::new(DigCommand {
+      tool: Some("spade".to_string()),
+    }));
+    assert_eq!(format!("{:?}", command), format!("{:?}", expected));
+    assert!(parser.parse("dig in sand", &game_state).is_err());
+  }
+
+  /// A command definition that makes "x" mean "inventory" rather than
+  /// "examine".
+  #[derive(Debug)]
+  struct Inventory;
+
+  impl CommandDefinition for Inventory {
+    fn verbs(&self) -> Vec<String> {
+      vec!["x".to_string()]
+    }
+
+    fn parse(&self, _action: &Action, _game_state: &GameState) -> Result<Command, ParserError> {
+      Ok(Command::Inventory(InventoryCommand))
+    }
+  }
+
+  #[test]
+  fn test_parse_registered_synonym() {
+    let mut parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert_eq!(
+      parser.parse("x leaflet", &game_state).unwrap(),
+      Command::Examine(ExamineCommand::new("leaflet"))
+    );
+    parser.register(Inventory);
+    assert_eq!(
+      parser.parse("x", &game_state).unwrap(),
+      Command::Inventory(InventoryCommand)
+    );
+    assert_eq!(
+      parser.parse("examine leaflet", &game_state).unwrap(),
+      Command::Examine(ExamineCommand::new("leaflet"))
+    );
+  }
+
+  #[test]
+  fn test_parse_say_and_write() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    game_state.world.objects.insert(
+      "paper".to_string(),
+      Object::new("paper", "").writable().at(Location::Player),
+    );
+    assert_eq!(
+      parser.parse("say \"Hello, Sailor!\"", &game_state).unwrap(),
+      Command::Say(SayCommand::new("Hello, Sailor!"))
+    );
+    assert_eq!(
+      parser.parse("Shout Hello, Sailor!", &game_state).unwrap(),
+      Command::Say(SayCommand::new_yell("Hello, Sailor!"))
+    );
+    assert_eq!(
+      parser.parse("write \"XYZZY\" on the paper", &game_state).unwrap(),
+      Command::Write(WriteCommand::new("XYZZY", "paper"))
+    );
+    assert_eq!(
+      parser.parse("write xyzzy on paper", &game_state).unwrap(),
+      Command::Write(WriteCommand::new("xyzzy", "paper"))
+    );
+    assert_eq!(
+      parser.parse("say", &game_state),
+      Err(ParserError::MissingDirectObject("say".to_string()))
+    );
+    assert_eq!(
+      parser.parse("write \"xyzzy\"", &game_state),
+      Err(ParserError::MissingIndirectObject {
+        verb: "write".to_string(),
+        preposition: "on".to_string(),
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_phrase() {
+    let mut parser = Parser::new();
+    let game_state = test_utils::test_state();
+    parser.add_phrase("Hello, Sailor!", Command::Wait(WaitCommand));
+    assert_eq!(
+      parser.parse("yell \"hello sailor\"", &game_state).unwrap(),
+      Command::Wait(WaitCommand)
+    );
+    assert_eq!(
+      parser.parse("say hello,   SAILOR", &game_state).unwrap(),
+      Command::Wait(WaitCommand)
+    );
+    assert_eq!(
+      parser.parse("say \"hello, sailors\"", &game_state).unwrap(),
+      Command::Say(SayCommand::new("hello, sailors"))
+    );
+  }
+
+  #[test]
+  fn test_parse_alias() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    assert_eq!(
+      parser.parse("alias KK = Kill Kobold with sword", &game_state).unwrap(),
+      Command::Alias(AliasCommand::new("kk", &["Kill Kobold with sword"]))
+    );
+    assert_eq!(
+      parser
+        .parse("macro dig=\" take shovel; dig. drop shovel \"", &game_state)
+        .unwrap(),
+      Command::Alias(AliasCommand::new("dig", &["take shovel; dig. drop shovel"]))
+    );
+    assert_eq!(
+      parser.parse("unalias kk", &game_state).unwrap(),
+      Command::Alias(AliasCommand::remove("kk"))
+    );
+    assert_eq!(
+      parser.parse("alias", &game_state).unwrap(),
+      Command::Aliases(AliasesCommand)
+    );
+    for input in [
+      "alias kk",
+      "alias = look",
+      "alias k k = look",
+      "macro dig = \" \"",
+      "macro dig = take shovel",
+      "macro dig = \"take shovel",
+    ] {
+      assert_eq!(
+        parser.parse(input, &game_state),
+        Err(ParserError::InvalidInput(input.to_string())),
+        "{}",
+        input
+      );
+    }
+  }
+
+  #[test]
+  fn test_parse_switch() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    for (input, command) in [
+      ("turn on the lamp", SwitchCommand::new_on("lantern")),
+      ("switch lamp off", SwitchCommand::new_off("lantern")),
+      ("light lantern", SwitchCommand::new_on("lantern")),
+      ("extinguish lantern", SwitchCommand::new_off("lantern")),
+    ] {
+      assert_eq!(
+        parser.parse(input, &game_state).unwrap(),
+        Command::Switch(command),
+        "{}",
+        input
+      );
+    }
+    assert!(parser.parse("turn lamp", &game_state).is_err());
+    // In the dark, only what the player is carrying can be found.
+    game_state.world.rooms.get_mut("west_of_house").unwrap().dark = true;
+    assert_eq!(
+      parser.parse("take mailbox", &game_state),
+      Err(ParserError::NotInScope(NounPhrase::new(&["mailbox"])))
+    );
+    assert_eq!(
+      parser.parse("take all", &game_state),
+      Err(ParserError::NoObjects("take".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_parse_open_and_lock() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    for (input, command) in [
+      ("open mailbox", Command::Open(OpenCommand::new("mailbox"))),
+      ("close the small mailbox", Command::Close(CloseCommand::new("mailbox"))),
+      (
+        "lock mailbox with lamp",
+        Command::Lock(LockCommand::new("mailbox", "lantern")),
+      ),
+      (
+        "unlock mailbox with lantern",
+        Command::Unlock(UnlockCommand::new("mailbox", "lantern")),
+      ),
+    ] {
+      assert_eq!(parser.parse(input, &game_state).unwrap(), command, "{}", input);
+    }
+    assert_eq!(
+      parser.parse("lock mailbox", &game_state),
+      Err(ParserError::MissingIndirectObject {
+        verb: "lock".to_string(),
+        preposition: "with".to_string(),
+      })
+    );
+    assert_eq!(
+      parser.parse("open", &game_state),
+      Err(ParserError::MissingDirectObject("open".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_parse_attack() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    game_state
+      .npcs
+      .insert("goblin".to_string(), Npc::new("big goblin", "").at("west_of_house"));
+    for (input, command) in [
+      (
+        "attack goblin",
+        AttackCommand::new(AttackTarget::Npc("goblin".to_string()), None),
+      ),
+      (
+        "kill the big goblin with the lamp",
+        AttackCommand::new(AttackTarget::Npc("goblin".to_string()), Some("lantern")),
+      ),
+      (
+        "hit mailbox",
+        AttackCommand::new(AttackTarget::Object("mailbox".to_string()), None),
+      ),
+    ] {
+      assert_eq!(
+        parser.parse(input, &game_state).unwrap(),
+        Command::Attack(command),
+        "{}",
+        input
+      );
+    }
+    assert_eq!(
+      parser.parse("attack goblin with", &game_state),
+      Err(ParserError::MissingIndirectObject {
+        verb: "attack".to_string(),
+        preposition: "with".to_string(),
+      })
+    );
+    game_state.world.player_location = "north_of_house".to_string();
+    assert_eq!(
+      parser.parse("attack goblin", &game_state),
+      Err(ParserError::NotInScope(NounPhrase::new(&["goblin"])))
+    );
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let parser = Parser::new();
+    let game_state = test_utils::test_state();
+    let verb = |verb: &str, preposition: &str| (verb.to_string(), preposition.to_string());
+    for (input, error) in [
+      (
+        "frobnicate lamp",
+        ParserError::UnknownVerb {
+          word: "frobnicate".to_string(),
+          suggestion: None,
+        },
+      ),
+      (
+        "take unicorn",
+        ParserError::UnknownNoun {
+          word: "unicorn".to_string(),
+          suggestion: None,
+        },
+      ),
+      (
+        "take small unicorn",
+        ParserError::UnknownNoun {
+          word: "unicorn".to_string(),
+          suggestion: None,
+        },
+      ),
+      (
+        "take elvish sword",
+        ParserError::NotInScope(NounPhrase::new(&["elvish", "sword"])),
+      ),
+      (
+        "take wooden mailbox",
+        ParserError::NotInScope(NounPhrase::new(&["wooden", "mailbox"])),
+      ),
+      ("drop it", ParserError::UnresolvedPronoun("it".to_string())),
+      ("take", ParserError::MissingDirectObject("take".to_string())),
+      ("get", ParserError::MissingDirectObject("take".to_string())),
+      ("save", ParserError::MissingDirectObject("save".to_string())),
+      (
+        "take from mailbox",
+        ParserError::MissingDirectObject("take".to_string()),
+      ),
+      ("look in", {
+        let (verb, preposition) = verb("look", "in");
+        ParserError::MissingIndirectObject { verb, preposition }
+      }),
+      ("put lamp", {
+        let (verb, preposition) = verb("put", "in");
+        ParserError::MissingIndirectObject { verb, preposition }
+      }),
+      ("take lamp with mailbox", {
+        let (verb, preposition) = verb("take", "with");
+        ParserError::BadPreposition { verb, preposition }
+      }),
+      ("look under table", {
+        let (verb, preposition) = verb("look", "under");
+        ParserError::BadPreposition { verb, preposition }
+      }),
+      (
+        "look in mailbox and box",
+        ParserError::TooManyObjects("look".to_string()),
+      ),
+      ("take leaflet and box", ParserError::TooManyObjects("take".to_string())),
+      ("drop all but lamp", ParserError::NoObjects("drop".to_string())),
+      ("look lamp", ParserError::InvalidInput("look lamp".to_string())),
+    ] {
+      assert_eq!(parser.parse(input, &game_state), Err(error), "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_parse_suggestions() {
+    let mut parser = Parser::new();
+    let game_state = test_utils::test_state();
+    let suggestion = |input: &str| {
+      let error = parser.parse(input, &game_state).unwrap_err();
+      error.suggestion().map(str::to_string)
+    };
+    assert_eq!(suggestion("tkae lamp"), Some("take".to_string()));
+    assert_eq!(suggestion("nrth"), Some("north".to_string()));
+    assert_eq!(suggestion("take lantren"), Some("lantern".to_string()));
+    assert_eq!(suggestion("take smal mailbox"), Some("small".to_string()));
+    // The sword isn't in scope, so it isn't suggested.
+    assert_eq!(suggestion("take swrod"), None);
+    assert_eq!(suggestion("dgi"), None);
+    parser.register(Dig);
+    assert_eq!(parser.parse("dgi", &game_state).unwrap_err().suggestion(), Some("dig"));
+  }
+
+  #[test]
+  fn test_apply_oops() {
+    let parser = Parser::new();
+    let mut game_state = test_utils::test_state();
+    assert_eq!(parser.apply_oops("take lamp", &game_state), None);
+    assert_eq!(
+      parser.apply_oops("oops lamp", &game_state),
+      Some(Err(ParserError::NothingToCorrect))
+    );
+    game_state.failed_input = Some(FailedInput {
+      input: "drop the lanten".to_string(),
+      word: "lanten".to_string(),
+    });
+    assert_eq!(
+      parser.apply_oops("OOPS lantern", &game_state),
+      Some(Ok("drop the lantern".to_string()))
+    );
+    assert!(matches!(parser.apply_oops("oops", &game_state), Some(Err(_))));
+  }
+
   #[test]
   fn test_parse_invalid() {
     let parser = Parser::new();
-    let command = parser.parse("invalid");
+    let game_state = GameState::default();
+    let command = parser.parse("invalid", &game_state);
     assert!(command.is_err());
   }
 }
@@ -0,0 +1,100 @@
+/// Count the single-character insertions, deletions, substitutions and
+/// transpositions of adjacent characters needed to turn one word into
+/// another.
+pub fn edit_distance(from: &str, to: &str) -> usize {
+  let from: Vec<char> = from.chars().collect();
+  let to: Vec<char> = to.chars().collect();
+  // distances[i][j] is the distance between from[..i] and to[..j].
+  let mut distances = vec![vec![0; to.len() + 1]; from.len() + 1];
+  for (i, row) in distances.iter_mut().enumerate() {
+    row[0] = i;
+  }
+  for (j, distance) in distances[0].iter_mut().enumerate() {
+    *distance = j;
+  }
+  for i in 1..=from.len() {
+    for j in 1..=to.len() {
+      let cost = usize::from(from[i - 1] != to[j - 1]);
+      let mut distance = (distances[i - 1][j] + 1)
+        .min(distances[i][j - 1] + 1)
+        .min(distances[i - 1][j - 1] + cost);
+      if i > 1 && j > 1 && from[i - 1] == to[j - 2] && from[i - 2] == to[j - 1] {
+        distance = distance.min(distances[i - 2][j - 2] + 1);
+      }
+      distances[i][j] = distance;
+    }
+  }
+  distances[from.len()][to.len()]
+}
+
+/// Suggest the word from the vocabulary that the player most likely meant,
+/// if any is close enough.
+///
+/// Short words are allowed one mistake and longer words two. When several
+/// words are equally close, the first in the vocabulary wins.
+pub fn suggest<'a>(word: &str, vocabulary: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+  let limit = if word.chars().count() <= 4 { 1 } else { 2 };
+  vocabulary
+    .into_iter()
+    .filter(|candidate| *candidate != word)
+    .map(|candidate| (edit_distance(word, candidate), candidate))
+    .filter(|(distance, _)| *distance <= limit)
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, candidate)| candidate)
+}
+
+/// Replace every occurrence of a word in the input (ignoring case and
+/// trailing punctuation) with another word.
+pub fn replace_word(input: &str, word: &str, replacement: &str) -> String {
+  input
+    .split_whitespace()
+    .map(|part| {
+      let trimmed = part.trim_end_matches([',', '.', '!', '?']);
+      if trimmed.eq_ignore_ascii_case(word) {
+        format!("{}{}", replacement, &part[trimmed.len()..])
+      } else {
+        part.to_string()
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_edit_distance() {
+    test_utils::init();
+    assert_eq!(edit_distance("lantern", "lantern"), 0);
+    assert_eq!(edit_distance("lanten", "lantern"), 1);
+    assert_eq!(edit_distance("lnatern", "lantern"), 1);
+    assert_eq!(edit_distance("lamtren", "lantern"), 2);
+    assert_eq!(edit_distance("", "lamp"), 4);
+    assert_eq!(edit_distance("sword", "lamp"), 5);
+  }
+
+  #[test]
+  fn test_suggest() {
+    test_utils::init();
+    let vocabulary = ["lamp", "lantern", "leaflet", "mailbox"];
+    assert_eq!(suggest("lanten", vocabulary), Some("lantern"));
+    assert_eq!(suggest("lmap", vocabulary), Some("lamp"));
+    assert_eq!(suggest("malbx", vocabulary), Some("mailbox"));
+    assert_eq!(suggest("lmp", vocabulary), Some("lamp"));
+    assert_eq!(suggest("lp", vocabulary), None);
+    assert_eq!(suggest("sword", vocabulary), None);
+  }
+
+  #[test]
+  fn test_replace_word() {
+    test_utils::init();
+    assert_eq!(
+      replace_word("take the Lanten.", "lanten", "lantern"),
+      "take the lantern."
+    );
+    assert_eq!(replace_word("take lamp", "lanten", "lantern"), "take lamp");
+  }
+}
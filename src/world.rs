@@ -0,0 +1,31 @@
+/// The world's clock.
+pub mod clock;
+/// Directions in which the player can move.
+pub mod direction;
+/// An error type for loading worlds.
+pub mod error;
+/// An exit from one room to another.
+pub mod exit;
+/// Where an object is in the game world.
+pub mod location;
+/// An object in the game world.
+pub mod object;
+/// A room in the game world.
+pub mod room;
+/// The game world itself.
+#[allow(clippy::module_inception)]
+pub mod world;
+
+/// The world prelude.
+pub mod prelude {
+  pub use crate::world::clock::Clock;
+  pub use crate::world::direction::Direction;
+  pub use crate::world::error::WorldError;
+  pub use crate::world::exit::Exit;
+  pub use crate::world::location::Location;
+  pub use crate::world::object::Object;
+  pub use crate::world::object::ObjectId;
+  pub use crate::world::room::Room;
+  pub use crate::world::room::RoomId;
+  pub use crate::world::world::World;
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of minutes in a day.
+pub const MINUTES_PER_DAY: u64 = 24 * 60;
+
+/// The time at which the game starts by default, in minutes after midnight
+/// (9:00 am).
+pub const DEFAULT_START: u64 = 9 * 60;
+
+/// The number of minutes each turn takes by default.
+pub const DEFAULT_MINUTES_PER_TURN: u64 = 1;
+
+/// The hour at which night ends (6:00 am).
+pub const DAWN: u64 = 6;
+
+/// The hour at which night begins (8:00 pm).
+pub const DUSK: u64 = 20;
+
+/// The world's clock, which counts the turns the player has taken and the
+/// time that has passed in the world.
+///
+/// Day/night, NPC schedules and timed puzzles all depend on this one clock,
+/// which the game loop advances after each command that takes time.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Clock {
+  /// The number of turns the player has taken.
+  pub turns: u64,
+  /// The time, in minutes since midnight at the start of the first day.
+  pub minutes: u64,
+  /// The number of minutes each turn takes.
+  pub minutes_per_turn: u64,
+}
+
+impl Clock {
+  /// Create a new clock, which starts at the specified time on the first
+  /// day.
+  pub fn new(hour: u64, minute: u64) -> Self {
+    Self {
+      minutes: hour * 60 + minute,
+      ..Self::default()
+    }
+  }
+
+  /// Make each turn take the specified number of minutes.
+  pub fn with_minutes_per_turn(mut self, minutes_per_turn: u64) -> Self {
+    self.minutes_per_turn = minutes_per_turn;
+    self
+  }
+
+  /// Advance the clock by one turn.
+  pub fn tick(&mut self) {
+    self.turns += 1;
+    self.minutes += self.minutes_per_turn;
+  }
+
+  /// The current day, starting from 1.
+  pub fn day(&self) -> u64 {
+    self.minutes / MINUTES_PER_DAY + 1
+  }
+
+  /// The current hour, from 0 to 23.
+  pub fn hour(&self) -> u64 {
+    self.minutes % MINUTES_PER_DAY / 60
+  }
+
+  /// The current minute of the hour.
+  pub fn minute(&self) -> u64 {
+    self.minutes % 60
+  }
+
+  /// Whether the current hour is at or after `start` and before `end`, where
+  /// the period may run past midnight (e.g. from 22 to 6).
+  pub fn is_between(&self, start: u64, end: u64) -> bool {
+    let hour = self.hour();
+    if start <= end {
+      start <= hour && hour < end
+    } else {
+      start <= hour || hour < end
+    }
+  }
+
+  /// Whether it is night.
+  pub fn is_night(&self) -> bool {
+    self.is_between(DUSK, DAWN)
+  }
+
+  /// The time of day as the player would read it (e.g. "9:05 am").
+  pub fn time_of_day(&self) -> String {
+    let (hour, meridiem) = match self.hour() {
+      0 => (12, "am"),
+      hour @ 1..=11 => (hour, "am"),
+      12 => (12, "pm"),
+      hour => (hour - 12, "pm"),
+    };
+    format!("{}:{:02} {}", hour, self.minute(), meridiem)
+  }
+
+  /// Fill in the time wherever it is called for in a description: "{time}"
+  /// becomes the time of day, "{day}" the day, and "{turns}" the number of
+  /// turns taken.
+  pub fn fill_in(&self, text: &str) -> String {
+    text
+      .replace("{time}", &self.time_of_day())
+      .replace("{day}", &self.day().to_string())
+      .replace("{turns}", &self.turns.to_string())
+  }
+}
+
+impl Default for Clock {
+  fn default() -> Self {
+    Self {
+      turns: 0,
+      minutes: DEFAULT_START,
+      minutes_per_turn: DEFAULT_MINUTES_PER_TURN,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_tick() {
+    test_utils::init();
+    let mut clock = Clock::new(23, 50).with_minutes_per_turn(15);
+    assert_eq!((clock.day(), clock.hour(), clock.minute()), (1, 23, 50));
+    clock.tick();
+    assert_eq!(clock.turns, 1);
+    assert_eq!((clock.day(), clock.hour(), clock.minute()), (2, 0, 5));
+  }
+
+  #[test]
+  fn test_is_between() {
+    test_utils::init();
+    assert!(Clock::new(9, 0).is_between(9, 17));
+    assert!(!Clock::new(17, 0).is_between(9, 17));
+    assert!(Clock::new(23, 0).is_between(22, 6));
+    assert!(Clock::new(5, 59).is_night());
+    assert!(!Clock::default().is_night());
+  }
+
+  #[test]
+  fn test_time_of_day() {
+    test_utils::init();
+    assert_eq!(Clock::default().time_of_day(), "9:00 am");
+    assert_eq!(Clock::new(0, 5).time_of_day(), "12:05 am");
+    assert_eq!(Clock::new(12, 30).time_of_day(), "12:30 pm");
+    assert_eq!(Clock::new(20, 0).time_of_day(), "8:00 pm");
+  }
+
+  #[test]
+  fn test_fill_in() {
+    test_utils::init();
+    let mut clock = Clock::new(21, 15);
+    clock.tick();
+    assert_eq!(
+      clock.fill_in("It is {time} on day {day}, after {turns} turn."),
+      "It is 9:16 pm on day 1, after 1 turn."
+    );
+  }
+}
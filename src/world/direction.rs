@@ -0,0 +1,152 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A compass, vertical, or relative direction.
+///
+/// Exits from rooms are keyed by name, so that a room may have exits like
+/// "house" or "window"; the directions here are simply the names that the
+/// parser knows how to abbreviate and that have sensible opposites.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+  /// North.
+  North,
+  /// Northeast.
+  Northeast,
+  /// East.
+  East,
+  /// Southeast.
+  Southeast,
+  /// South.
+  South,
+  /// Southwest.
+  Southwest,
+  /// West.
+  West,
+  /// Northwest.
+  Northwest,
+  /// Up.
+  Up,
+  /// Down.
+  Down,
+  /// In.
+  In,
+  /// Out.
+  Out,
+}
+
+impl Direction {
+  /// All of the directions.
+  pub const ALL: [Direction; 12] = [
+    Direction::North,
+    Direction::Northeast,
+    Direction::East,
+    Direction::Southeast,
+    Direction::South,
+    Direction::Southwest,
+    Direction::West,
+    Direction::Northwest,
+    Direction::Up,
+    Direction::Down,
+    Direction::In,
+    Direction::Out,
+  ];
+
+  /// Interpret a word (e.g. "north" or "n") as a direction.
+  pub fn from_word(word: &str) -> Option<Self> {
+    match word {
+      "north" | "n" => Some(Direction::North),
+      "northeast" | "ne" => Some(Direction::Northeast),
+      "east" | "e" => Some(Direction::East),
+      "southeast" | "se" => Some(Direction::Southeast),
+      "south" | "s" => Some(Direction::South),
+      "southwest" | "sw" => Some(Direction::Southwest),
+      "west" | "w" => Some(Direction::West),
+      "northwest" | "nw" => Some(Direction::Northwest),
+      "up" | "u" => Some(Direction::Up),
+      "down" | "d" => Some(Direction::Down),
+      "in" => Some(Direction::In),
+      "out" => Some(Direction::Out),
+      _ => None,
+    }
+  }
+
+  /// The canonical name of the direction, as used for room exits.
+  pub fn name(&self) -> &'static str {
+    match self {
+      Direction::North => "north",
+      Direction::Northeast => "northeast",
+      Direction::East => "east",
+      Direction::Southeast => "southeast",
+      Direction::South => "south",
+      Direction::Southwest => "southwest",
+      Direction::West => "west",
+      Direction::Northwest => "northwest",
+      Direction::Up => "up",
+      Direction::Down => "down",
+      Direction::In => "in",
+      Direction::Out => "out",
+    }
+  }
+
+  /// The opposite direction.
+  pub fn opposite(&self) -> Self {
+    match self {
+      Direction::North => Direction::South,
+      Direction::Northeast => Direction::Southwest,
+      Direction::East => Direction::West,
+      Direction::Southeast => Direction::Northwest,
+      Direction::South => Direction::North,
+      Direction::Southwest => Direction::Northeast,
+      Direction::West => Direction::East,
+      Direction::Northwest => Direction::Southeast,
+      Direction::Up => Direction::Down,
+      Direction::Down => Direction::Up,
+      Direction::In => Direction::Out,
+      Direction::Out => Direction::In,
+    }
+  }
+}
+
+impl Display for Direction {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_from_word() {
+    test_utils::init();
+    assert_eq!(Direction::from_word("north"), Some(Direction::North));
+    assert_eq!(Direction::from_word("ne"), Some(Direction::Northeast));
+    assert_eq!(Direction::from_word("u"), Some(Direction::Up));
+    assert_eq!(Direction::from_word("in"), Some(Direction::In));
+    assert_eq!(Direction::from_word("house"), None);
+  }
+
+  #[test]
+  fn test_name_round_trip() {
+    test_utils::init();
+    for direction in Direction::ALL {
+      assert_eq!(Direction::from_word(direction.name()), Some(direction));
+    }
+  }
+
+  #[test]
+  fn test_opposite() {
+    test_utils::init();
+    for direction in Direction::ALL {
+      assert_eq!(direction.opposite().opposite(), direction);
+    }
+    assert_eq!(Direction::Up.opposite(), Direction::Down);
+  }
+
+  #[test]
+  fn test_display() {
+    test_utils::init();
+    assert_eq!(Direction::Southwest.to_string(), "southwest");
+  }
+}
@@ -0,0 +1,63 @@
+use std::io::Error as IoError;
+use thiserror::Error as ThisError;
+
+/// An error that can occur when loading a world definition.
+///
+/// This error type is used to represent problems with the world file itself,
+/// whether it cannot be read, is not well-formed, or describes a world that
+/// does not make sense (e.g. an exit leading to a room that does not exist).
+#[derive(ThisError, Debug)]
+pub enum WorldError {
+  /// Conversion from an IO error. This is not a result of a problem with the
+  /// world definition itself, but rather a problem with reading it.
+  #[error("An I/O error occurred: {0}")]
+  IoError(#[from] IoError),
+  /// The world definition could not be parsed.
+  #[error("Syntax error at line {line}, column {column}: {message}")]
+  SyntaxError {
+    /// The line on which the error occurred, starting from 1.
+    line: usize,
+    /// The column at which the error occurred, starting from 1.
+    column: usize,
+    /// A description of the error.
+    message: String,
+  },
+  /// The world definition was parsed, but describes an invalid world.
+  #[error("Invalid world: {0}")]
+  InvalidWorld(String),
+}
+
+impl WorldError {
+  /// Create a syntax error from a byte offset within the source.
+  pub fn syntax_error(source: &str, offset: usize, message: &str) -> Self {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+    WorldError::SyntaxError {
+      line,
+      column,
+      message: message.to_string(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_syntax_error() {
+    test_utils::init();
+    let source = "a = 1\nb = oops\n";
+    let error = WorldError::syntax_error(source, 10, "invalid string");
+    assert_eq!(error.to_string(), "Syntax error at line 2, column 5: invalid string");
+  }
+
+  #[test]
+  fn test_syntax_error_start() {
+    test_utils::init();
+    let error = WorldError::syntax_error("oops", 0, "invalid");
+    assert!(matches!(error, WorldError::SyntaxError { line: 1, column: 1, .. }));
+  }
+}
@@ -0,0 +1,81 @@
+use crate::world::object::ObjectId;
+use crate::world::room::RoomId;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// An exit from one room to another.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Exit {
+  /// The room on the other side of the exit.
+  pub destination: RoomId,
+  /// The door that gates the exit, if any, which must be open to pass.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub door: Option<ObjectId>,
+}
+
+impl Exit {
+  /// Create a new exit leading to the specified room.
+  pub fn new(destination: &str) -> Self {
+    Self {
+      destination: destination.to_string(),
+      door: None,
+    }
+  }
+
+  /// Gate the exit with a door.
+  pub fn with_door(mut self, door: &str) -> Self {
+    self.door = Some(door.to_string());
+    self
+  }
+}
+
+/// An exit may be written in a world file either in full, as a table, or as
+/// just the identifier of the room it leads to.
+impl<'de> Deserialize<'de> for Exit {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct FullExit {
+      destination: RoomId,
+      #[serde(default)]
+      door: Option<ObjectId>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ExitDefinition {
+      Destination(RoomId),
+      Full(FullExit),
+    }
+
+    Ok(match ExitDefinition::deserialize(deserializer)? {
+      ExitDefinition::Destination(destination) => Exit::new(&destination),
+      ExitDefinition::Full(FullExit { destination, door }) => Exit { destination, door },
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use std::collections::BTreeMap;
+
+  #[test]
+  fn test_new() {
+    test_utils::init();
+    let exit = Exit::new("north_of_house");
+    assert_eq!(exit.destination, "north_of_house");
+  }
+
+  #[test]
+  fn test_deserialize() {
+    test_utils::init();
+    let exits: BTreeMap<String, Exit> =
+      toml::from_str("north = \"north_of_house\"\nsouth = { destination = \"south_of_house\" }").unwrap();
+    assert_eq!(exits["north"], Exit::new("north_of_house"));
+    assert_eq!(exits["south"], Exit::new("south_of_house"));
+    let exits: BTreeMap<String, Exit> =
+      toml::from_str("down = { destination = \"cellar\", door = \"trap_door\" }").unwrap();
+    assert_eq!(exits["down"], Exit::new("cellar").with_door("trap_door"));
+  }
+}
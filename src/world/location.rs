@@ -0,0 +1,33 @@
+use crate::world::object::ObjectId;
+use crate::world::room::RoomId;
+use serde::{Deserialize, Serialize};
+
+/// Where an object is in the game world.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Location {
+  /// The object is not anywhere in the world (e.g. it has been destroyed, or
+  /// has not yet appeared).
+  #[default]
+  Nowhere,
+  /// The object is in a room.
+  Room(RoomId),
+  /// The object is carried by the player.
+  Player,
+  /// The object is inside another object (e.g. a box).
+  In(ObjectId),
+  /// The object is on top of another object (e.g. a table).
+  On(ObjectId),
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_default() {
+    test_utils::init();
+    assert_eq!(Location::default(), Location::Nowhere);
+  }
+}
@@ -0,0 +1,294 @@
+use crate::world::location::Location;
+use serde::{Deserialize, Serialize};
+
+/// The unique identifier of an object.
+pub type ObjectId = String;
+
+/// An object in the game world.
+///
+/// Objects are the things the player can see, take, drop, and put into or
+/// onto other objects. Whether an object can hold other objects is decided by
+/// a handful of flags rather than by any kind of type hierarchy.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Object {
+  /// The name of the object (e.g. "brass lantern").
+  pub name: String,
+  /// The description of the object.
+  pub description: String,
+  /// Where the object currently is.
+  pub location: Location,
+  /// Whether the object is fixed in place and cannot be taken.
+  pub fixed: bool,
+  /// Whether other objects can be put inside this object.
+  pub container: bool,
+  /// Whether other objects can be put on top of this object.
+  pub supporter: bool,
+  /// Whether the player can open and close the object (e.g. a door or a
+  /// box).
+  pub openable: bool,
+  /// Whether the object is closed, hiding and protecting its contents, or
+  /// (for a door) barring the way.
+  pub closed: bool,
+  /// Whether the object is locked, so that it can't be opened.
+  pub locked: bool,
+  /// The key that locks and unlocks the object, if it has a lock.
+  pub key: Option<ObjectId>,
+  /// The maximum number of objects this object can hold, if limited.
+  pub capacity: Option<usize>,
+  /// Nouns that refer to the object, in addition to the last word of its
+  /// name (e.g. "lamp" for the brass lantern).
+  pub nouns: Vec<String>,
+  /// Adjectives that describe the object, in addition to the other words of
+  /// its name (e.g. "shiny" for the brass lantern).
+  pub adjectives: Vec<String>,
+  /// Whether the object can give off light when switched on.
+  pub light_source: bool,
+  /// Whether the object is switched on.
+  pub lit: bool,
+  /// Whether the player can write on the object.
+  pub writable: bool,
+  /// What has been written on the object, in the order it was written.
+  pub writing: Vec<String>,
+  /// The damage the object does as a weapon, or 0 if it isn't one.
+  pub damage: u32,
+  /// The damage the object turns aside from each blow while the player is
+  /// carrying it (e.g. a shield).
+  pub armor: u32,
+}
+
+impl Object {
+  /// Create a new object, which is nowhere in particular.
+  pub fn new(name: &str, description: &str) -> Self {
+    Self {
+      name: name.to_string(),
+      description: description.to_string(),
+      ..Self::default()
+    }
+  }
+
+  /// Place the object at the specified location.
+  pub fn at(mut self, location: Location) -> Self {
+    self.location = location;
+    self
+  }
+
+  /// Fix the object in place.
+  pub fn fixed(mut self) -> Self {
+    self.fixed = true;
+    self
+  }
+
+  /// Allow other objects to be put inside this object.
+  pub fn container(mut self) -> Self {
+    self.container = true;
+    self
+  }
+
+  /// Allow other objects to be put on top of this object.
+  pub fn supporter(mut self) -> Self {
+    self.supporter = true;
+    self
+  }
+
+  /// Allow the player to open and close the object.
+  pub fn openable(mut self) -> Self {
+    self.openable = true;
+    self
+  }
+
+  /// Close the object.
+  pub fn closed(mut self) -> Self {
+    self.closed = true;
+    self
+  }
+
+  /// Give the object a lock, which the specified key locks and unlocks.
+  pub fn with_key(mut self, key: &str) -> Self {
+    self.key = Some(key.to_string());
+    self
+  }
+
+  /// Lock the object, which also closes it.
+  pub fn locked(mut self) -> Self {
+    self.closed = true;
+    self.locked = true;
+    self
+  }
+
+  /// Allow the object to give off light.
+  pub fn light_source(mut self) -> Self {
+    self.light_source = true;
+    self
+  }
+
+  /// Switch the object on.
+  pub fn lit(mut self) -> Self {
+    self.lit = true;
+    self
+  }
+
+  /// Allow the player to write on the object.
+  pub fn writable(mut self) -> Self {
+    self.writable = true;
+    self
+  }
+
+  /// Make the object a weapon that does the specified damage.
+  pub fn with_damage(mut self, damage: u32) -> Self {
+    self.damage = damage;
+    self
+  }
+
+  /// Make the object armor that turns aside the specified damage.
+  pub fn with_armor(mut self, armor: u32) -> Self {
+    self.armor = armor;
+    self
+  }
+
+  /// Limit the number of objects this object can hold.
+  pub fn with_capacity(mut self, capacity: usize) -> Self {
+    self.capacity = Some(capacity);
+    self
+  }
+
+  /// Add nouns that refer to the object.
+  pub fn with_nouns(mut self, nouns: &[&str]) -> Self {
+    self.nouns.extend(nouns.iter().map(|noun| noun.to_string()));
+    self
+  }
+
+  /// Add adjectives that describe the object.
+  pub fn with_adjectives(mut self, adjectives: &[&str]) -> Self {
+    self
+      .adjectives
+      .extend(adjectives.iter().map(|adjective| adjective.to_string()));
+    self
+  }
+
+  /// Describe whether the object is open or closed, if it can be opened
+  /// (e.g. "The wooden door is closed.").
+  pub fn describe_openness(&self) -> Option<String> {
+    if !self.openable {
+      return None;
+    }
+    let state = if self.closed { "closed" } else { "open" };
+    Some(format!("The {} is {}.", self.name, state))
+  }
+
+  /// Whether the object is giving off light.
+  pub fn gives_light(&self) -> bool {
+    self.light_source && self.lit
+  }
+
+  /// Whether the player can see (and reach) the contents of this object.
+  pub fn reveals_contents(&self) -> bool {
+    self.supporter || (self.container && !self.closed)
+  }
+
+  /// Whether the word is a noun that refers to this object.
+  ///
+  /// The last word of the object's name is always a noun.
+  pub fn has_noun(&self, word: &str) -> bool {
+    self.name.split_whitespace().last() == Some(word) || self.nouns.iter().any(|noun| noun == word)
+  }
+
+  /// Whether the word is an adjective that describes this object.
+  ///
+  /// Every word of the object's name but the last is always an adjective.
+  pub fn has_adjective(&self, word: &str) -> bool {
+    let mut name = self.name.split_whitespace().rev().skip(1);
+    name.any(|part| part == word) || self.adjectives.iter().any(|adjective| adjective == word)
+  }
+
+  /// Whether the player's words could refer to this object.
+  ///
+  /// The last word must be one of the object's nouns, and any words before it
+  /// must be among its adjectives, so "lantern", "brass lantern" and "lamp"
+  /// can all refer to the brass lantern.
+  pub fn matches(&self, words: &[&str]) -> bool {
+    match words.split_last() {
+      Some((noun, adjectives)) => {
+        self.has_noun(noun) && adjectives.iter().all(|adjective| self.has_adjective(adjective))
+      },
+      None => false,
+    }
+  }
+
+  /// The name of the object with an indefinite article (e.g. "a brass
+  /// lantern").
+  pub fn indefinite_name(&self) -> String {
+    match self.name.chars().next() {
+      Some('a' | 'e' | 'i' | 'o' | 'u') => format!("an {}", self.name),
+      _ => format!("a {}", self.name),
+    }
+  }
+
+  /// The name of the object with a definite article (e.g. "the brass
+  /// lantern").
+  pub fn definite_name(&self) -> String {
+    format!("the {}", self.name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_new() {
+    test_utils::init();
+    let object = Object::new("brass lantern", "A battery-powered brass lantern.");
+    assert_eq!(object.name, "brass lantern");
+    assert_eq!(object.location, Location::Nowhere);
+    assert!(!object.fixed);
+    assert!(!object.container);
+  }
+
+  #[test]
+  fn test_reveals_contents() {
+    test_utils::init();
+    assert!(!Object::new("rock", "A rock.").reveals_contents());
+    assert!(Object::new("box", "A box.").container().reveals_contents());
+    assert!(!Object::new("box", "A box.").container().closed().reveals_contents());
+    assert!(Object::new("table", "A table.").supporter().reveals_contents());
+  }
+
+  #[test]
+  fn test_matches() {
+    test_utils::init();
+    let object = Object::new("brass lantern", "A battery-powered brass lantern.");
+    assert!(object.matches(&["lantern"]));
+    assert!(object.matches(&["brass", "lantern"]));
+    assert!(!object.matches(&["iron", "lantern"]));
+    assert!(!object.matches(&["brass"]));
+    assert!(!object.matches(&["lantern", "brass"]));
+    assert!(!object.matches(&[]));
+  }
+
+  #[test]
+  fn test_matches_nouns_and_adjectives() {
+    test_utils::init();
+    let object = Object::new("brass lantern", "A battery-powered brass lantern.")
+      .with_nouns(&["lamp", "light"])
+      .with_adjectives(&["shiny"]);
+    assert!(object.matches(&["lamp"]));
+    assert!(object.matches(&["light"]));
+    assert!(object.matches(&["shiny", "brass", "lamp"]));
+    assert!(!object.matches(&["lamp", "light"]));
+    assert!(object.has_noun("lantern"));
+    assert!(!object.has_noun("brass"));
+    assert!(object.has_adjective("brass"));
+    assert!(object.has_adjective("shiny"));
+    assert!(!object.has_adjective("lantern"));
+  }
+
+  #[test]
+  fn test_names() {
+    test_utils::init();
+    assert_eq!(Object::new("brass lantern", "").indefinite_name(), "a brass lantern");
+    assert_eq!(Object::new("elvish sword", "").indefinite_name(), "an elvish sword");
+    assert_eq!(Object::new("elvish sword", "").definite_name(), "the elvish sword");
+  }
+}
@@ -0,0 +1,124 @@
+use crate::world::clock::Clock;
+use crate::world::exit::Exit;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The unique identifier of a room.
+pub type RoomId = String;
+
+/// A room in the game world.
+///
+/// A "room" is any discrete location the player can occupy, whether it is an
+/// actual room, a forest clearing, or the deck of a ship.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Room {
+  /// The short name of the room (e.g. "West of House").
+  pub name: String,
+  /// The full description of the room.
+  pub description: String,
+  /// The full description of the room at night, if it differs.
+  pub night_description: Option<String>,
+  /// The exits from the room, keyed by the name of the exit (e.g. "north").
+  pub exits: BTreeMap<String, Exit>,
+  /// Whether the room is dark, so that the player needs a light source to
+  /// see in it.
+  pub dark: bool,
+}
+
+impl Room {
+  /// Create a new room with no exits.
+  pub fn new(name: &str, description: &str) -> Self {
+    Self {
+      name: name.to_string(),
+      description: description.to_string(),
+      night_description: None,
+      exits: BTreeMap::new(),
+      dark: false,
+    }
+  }
+
+  /// Add an exit to the room.
+  pub fn with_exit(mut self, name: &str, destination: &str) -> Self {
+    self.exits.insert(name.to_string(), Exit::new(destination));
+    self
+  }
+
+  /// Describe the room differently at night.
+  pub fn with_night_description(mut self, night_description: &str) -> Self {
+    self.night_description = Some(night_description.to_string());
+    self
+  }
+
+  /// Add an exit to the room, gated by a door.
+  pub fn with_exit_through(mut self, name: &str, destination: &str, door: &str) -> Self {
+    self
+      .exits
+      .insert(name.to_string(), Exit::new(destination).with_door(door));
+    self
+  }
+
+  /// Make the room dark.
+  pub fn dark(mut self) -> Self {
+    self.dark = true;
+    self
+  }
+
+  /// Get the exit with the specified name, if any.
+  pub fn exit(&self, name: &str) -> Option<&Exit> {
+    self.exits.get(name)
+  }
+
+  /// Describe the room to the player at the time shown by the clock.
+  pub fn describe(&self, clock: &Clock) -> String {
+    let description = match &self.night_description {
+      Some(night_description) if clock.is_night() => night_description,
+      _ => &self.description,
+    };
+    format!("{}\n{}", self.name, clock.fill_in(description))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+
+  #[test]
+  fn test_new() {
+    test_utils::init();
+    let room = Room::new("West of House", "You are standing in an open field.");
+    assert_eq!(room.name, "West of House");
+    assert_eq!(room.description, "You are standing in an open field.");
+    assert!(room.exits.is_empty());
+  }
+
+  #[test]
+  fn test_with_exit() {
+    test_utils::init();
+    let room = Room::new("West of House", "You are standing in an open field.").with_exit("north", "north_of_house");
+    assert_eq!(room.exit("north"), Some(&Exit::new("north_of_house")));
+    assert_eq!(room.exit("south"), None);
+  }
+
+  #[test]
+  fn test_describe() {
+    test_utils::init();
+    let room = Room::new("West of House", "You are standing in an open field.");
+    assert_eq!(
+      room.describe(&Clock::default()),
+      "West of House\nYou are standing in an open field."
+    );
+  }
+
+  #[test]
+  fn test_describe_at_night() {
+    test_utils::init();
+    let room = Room::new("Clearing", "It is {time}.").with_night_description("The stars are out at {time}.");
+    assert_eq!(room.describe(&Clock::new(12, 0)), "Clearing\nIt is 12:00 pm.");
+    assert_eq!(
+      room.describe(&Clock::new(22, 0)),
+      "Clearing\nThe stars are out at 10:00 pm."
+    );
+  }
+}
@@ -0,0 +1,787 @@
+use crate::world::clock::Clock;
+use crate::world::error::WorldError;
+use crate::world::exit::Exit;
+use crate::world::location::Location;
+use crate::world::object::{Object, ObjectId};
+use crate::world::room::{Room, RoomId};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::path::Path;
+
+/// What the player sees in the dark, unless the world has a "dark" message.
+pub const DARKNESS: &str = "It is pitch black.";
+
+/// The game world.
+///
+/// This holds everything that exists in the game, as well as the player's
+/// place within it.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct World {
+  /// The room the player is currently in.
+  pub player_location: RoomId,
+  /// Messages that are part of the game's content rather than its logic,
+  /// keyed by name (e.g. "intro").
+  pub messages: BTreeMap<String, String>,
+  /// The rooms in the world, keyed by their unique identifiers.
+  pub rooms: BTreeMap<RoomId, Room>,
+  /// The objects in the world, keyed by their unique identifiers.
+  pub objects: BTreeMap<ObjectId, Object>,
+  /// The world's clock.
+  pub clock: Clock,
+}
+
+impl World {
+  /// Create a new, empty world.
+  pub fn new() -> Self {
+    Self {
+      player_location: RoomId::new(),
+      messages: BTreeMap::new(),
+      rooms: BTreeMap::new(),
+      objects: BTreeMap::new(),
+      clock: Clock::default(),
+    }
+  }
+
+  /// Load a world from a world file.
+  ///
+  /// See [`World::from_toml`] for a description of the format.
+  pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, WorldError> {
+    let source = fs::read_to_string(path)?;
+    Self::from_toml(&source)
+  }
+
+  /// Parse a world from the contents of a world file.
+  ///
+  /// World files are written in [TOML](https://toml.io). Every top-level key
+  /// is optional, and every room and object is identified by its key, which
+  /// is how exits and locations refer to it:
+  ///
+  /// ```toml
+  /// # The room in which the player starts.
+  /// player_location = "west_of_house"
+  ///
+  /// # The clock, which starts at 9:00 am by default. Its time is in minutes
+  /// # since midnight at the start of the first day.
+  /// [clock]
+  /// minutes = 480
+  /// minutes_per_turn = 1
+  ///
+  /// # Messages, keyed by name. The "intro" message is shown when the game
+  /// # starts, the "dark" message is shown instead of dark rooms, and the
+  /// # "death" message is shown when the player dies.
+  /// [messages]
+  /// intro = "Welcome to Sork!"
+  ///
+  /// [rooms.west_of_house]
+  /// name = "West of House"
+  /// # Descriptions may call for "{time}", "{day}" or "{turns}".
+  /// description = "You are standing in an open field west of a white house."
+  /// # Used instead of the description at night, if given.
+  /// night_description = "You are standing in a dark field west of a white house."
+  /// # Dark rooms need a light source to see in. Rooms are lit by default.
+  /// dark = false
+  /// # Exits are keyed by name; compass directions are spelled out in full.
+  /// # An exit is either the key of a room or a table with a `destination`
+  /// # and, optionally, the `door` (a fixed object) that gates it.
+  /// exits = { north = "north_of_house", south = { destination = "south_of_house" } }
+  ///
+  /// [objects.mailbox]
+  /// name = "small mailbox"
+  /// description = "It's a small mailbox."
+  /// # One of "nowhere", "player", { room = "..." }, { in = "..." } or
+  /// # { on = "..." }. Objects are nowhere unless otherwise specified.
+  /// location = { room = "west_of_house" }
+  /// # The following flags default to false.
+  /// fixed = true
+  /// container = true
+  /// supporter = false
+  /// closed = false
+  /// openable = false
+  /// locked = false
+  /// light_source = false
+  /// # Whether a light source is switched on.
+  /// lit = false
+  /// # The damage it does as a weapon, and the damage it turns aside while
+  /// # the player carries it. Both default to 0.
+  /// damage = 0
+  /// armor = 0
+  /// # The maximum number of objects it can hold; unlimited if omitted.
+  /// capacity = 3
+  /// # The key that locks and unlocks it; it has no lock if omitted.
+  /// key = "small_key"
+  /// # Words the player can use to refer to it, besides those in its name.
+  /// nouns = ["box"]
+  /// adjectives = ["tin"]
+  /// ```
+  ///
+  /// Syntax errors are reported with the line and column at which they
+  /// occurred. The world is then checked for consistency, so that e.g. an
+  /// exit cannot lead to a room that does not exist.
+  pub fn from_toml(source: &str) -> Result<Self, WorldError> {
+    let world: World = toml::from_str(source).map_err(|error| {
+      let offset = error.span().map(|span| span.start).unwrap_or_default();
+      WorldError::syntax_error(source, offset, error.message())
+    })?;
+    world.validate()?;
+    Ok(world)
+  }
+
+  /// Check that everything in the world refers to things that exist.
+  pub fn validate(&self) -> Result<(), WorldError> {
+    if !self.player_location.is_empty() && self.room(&self.player_location).is_none() {
+      return Err(WorldError::InvalidWorld(format!(
+        "The player starts in a nonexistent room '{}'.",
+        self.player_location
+      )));
+    }
+    for (id, room) in &self.rooms {
+      for (name, exit) in &room.exits {
+        if self.room(&exit.destination).is_none() {
+          return Err(WorldError::InvalidWorld(format!(
+            "The exit '{}' from room '{}' leads to a nonexistent room '{}'.",
+            name, id, exit.destination
+          )));
+        }
+        if let Some(door) = &exit.door {
+          if !self.object(door).is_some_and(|door| door.fixed) {
+            return Err(WorldError::InvalidWorld(format!(
+              "The exit '{}' from room '{}' is gated by '{}', which is not a fixed object.",
+              name, id, door
+            )));
+          }
+        }
+      }
+    }
+    for (id, object) in &self.objects {
+      let valid = match &object.location {
+        Location::Nowhere | Location::Player => true,
+        Location::Room(room) => self.room(room).is_some(),
+        Location::In(parent) | Location::On(parent) => parent != id && self.object(parent).is_some(),
+      };
+      if !valid {
+        return Err(WorldError::InvalidWorld(format!(
+          "The object '{}' is in a nonexistent location {:?}.",
+          id, object.location
+        )));
+      }
+      // Objects inside or on each other would never be found.
+      let mut visited = vec![id];
+      let mut location = &object.location;
+      while let Location::In(parent) | Location::On(parent) = location {
+        if visited.contains(&parent) {
+          return Err(WorldError::InvalidWorld(format!(
+            "The object '{}' is inside or on itself, by way of '{}'.",
+            id, parent
+          )));
+        }
+        visited.push(parent);
+        match self.object(parent) {
+          Some(parent) => location = &parent.location,
+          None => break,
+        }
+      }
+      if let Some(key) = object.key.as_ref().filter(|key| self.object(key).is_none()) {
+        return Err(WorldError::InvalidWorld(format!(
+          "The object '{}' is locked by a nonexistent key '{}'.",
+          id, key
+        )));
+      }
+    }
+    Ok(())
+  }
+
+  /// Get a message by name, if it exists.
+  pub fn message(&self, name: &str) -> Option<&str> {
+    self.messages.get(name).map(String::as_str)
+  }
+
+  /// Add a room to the world.
+  pub fn with_room(mut self, id: &str, room: Room) -> Self {
+    self.add_room(id, room);
+    self
+  }
+
+  /// Add an object to the world.
+  pub fn with_object(mut self, id: &str, object: Object) -> Self {
+    self.add_object(id, object);
+    self
+  }
+
+  /// Replace the world's clock.
+  pub fn with_clock(mut self, clock: Clock) -> Self {
+    self.clock = clock;
+    self
+  }
+
+  /// Set the player's location.
+  pub fn with_player_location(mut self, id: &str) -> Self {
+    self.player_location = id.to_string();
+    self
+  }
+
+  /// Add a room to the world.
+  pub fn add_room(&mut self, id: &str, room: Room) {
+    self.rooms.insert(id.to_string(), room);
+  }
+
+  /// Add an object to the world.
+  pub fn add_object(&mut self, id: &str, object: Object) {
+    self.objects.insert(id.to_string(), object);
+  }
+
+  /// Get the room with the specified identifier, if any.
+  pub fn room(&self, id: &str) -> Option<&Room> {
+    self.rooms.get(id)
+  }
+
+  /// Whether an exit can be passed through, which it can unless its door is
+  /// closed.
+  pub fn is_passable(&self, exit: &Exit) -> bool {
+    !exit
+      .door
+      .as_ref()
+      .and_then(|door| self.object(door))
+      .is_some_and(|door| door.closed)
+  }
+
+  /// Find the shortest route from one room to another through passable
+  /// exits, as the names of the exits to take in order, or `None` if there
+  /// is no such route.
+  pub fn route(&self, from: &str, to: &str) -> Option<Vec<String>> {
+    let mut previous: BTreeMap<&str, (&str, &str)> = BTreeMap::new();
+    let mut queue = VecDeque::from([from]);
+    while let Some(id) = queue.pop_front() {
+      if id == to {
+        let mut route = Vec::new();
+        let mut id = to;
+        while let Some((room, exit)) = previous.get(id) {
+          route.push(exit.to_string());
+          id = room;
+        }
+        route.reverse();
+        return Some(route);
+      }
+      for (name, exit) in self.room(id).into_iter().flat_map(|room| room.exits.iter()) {
+        let destination = exit.destination.as_str();
+        if destination != from && !previous.contains_key(destination) && self.is_passable(exit) {
+          previous.insert(destination, (id, name));
+          queue.push_back(destination);
+        }
+      }
+    }
+    None
+  }
+
+  /// Get the room the player is currently in, if any.
+  pub fn current_room(&self) -> Option<&Room> {
+    self.room(&self.player_location)
+  }
+
+  /// Get the object with the specified identifier, if any.
+  pub fn object(&self, id: &str) -> Option<&Object> {
+    self.objects.get(id)
+  }
+
+  /// Get a mutable reference to the object with the specified identifier.
+  pub fn object_mut(&mut self, id: &str) -> Option<&mut Object> {
+    self.objects.get_mut(id)
+  }
+
+  /// Get the identifiers of the objects at the specified location.
+  pub fn contents(&self, location: &Location) -> Vec<ObjectId> {
+    self
+      .objects
+      .iter()
+      .filter(|(_, object)| object.location == *location)
+      .map(|(id, _)| id.clone())
+      .collect()
+  }
+
+  /// Get the identifiers of the objects the player is carrying.
+  pub fn inventory(&self) -> Vec<ObjectId> {
+    self.contents(&Location::Player)
+  }
+
+  /// Whether the first object is (directly or indirectly) inside or on top of
+  /// the second object.
+  pub fn is_within(&self, id: &str, ancestor: &str) -> bool {
+    let mut current = self.object(id);
+    while let Some(object) = current {
+      match &object.location {
+        Location::In(parent) | Location::On(parent) => {
+          if parent == ancestor {
+            return true;
+          }
+          current = self.object(parent);
+        },
+        _ => return false,
+      }
+    }
+    false
+  }
+
+  /// Get the identifiers of the objects the player can see and reach.
+  ///
+  /// This includes everything in the current room or carried by the player,
+  /// as well as the contents of any open containers and supporters among
+  /// those objects. In the dark, the player can only feel what they are
+  /// carrying.
+  pub fn objects_in_scope(&self) -> Vec<ObjectId> {
+    if self.is_lit() {
+      self.objects_around_player()
+    } else {
+      self.with_contents(self.inventory())
+    }
+  }
+
+  /// Whether the player can see, because the current room is naturally lit
+  /// or something around the player is giving off light.
+  pub fn is_lit(&self) -> bool {
+    let dark = self.current_room().is_some_and(|room| room.dark);
+    !dark
+      || self
+        .objects_around_player()
+        .iter()
+        .any(|id| self.object(id).is_some_and(Object::gives_light))
+  }
+
+  /// Get the identifiers of the doors gating the exits from the current
+  /// room.
+  ///
+  /// Doors belong to both of the rooms they connect, rather than being in
+  /// either of them.
+  pub fn doors(&self) -> Vec<ObjectId> {
+    let mut doors: Vec<ObjectId> = Vec::new();
+    let exits = self.current_room().into_iter().flat_map(|room| room.exits.values());
+    for door in exits.filter_map(|exit| exit.door.as_ref()) {
+      if !doors.contains(door) {
+        doors.push(door.clone());
+      }
+    }
+    doors
+  }
+
+  /// Get the identifiers of the objects around the player, as they would
+  /// be seen in the light.
+  fn objects_around_player(&self) -> Vec<ObjectId> {
+    let mut scope = self.contents(&Location::Room(self.player_location.clone()));
+    scope.extend(self.doors());
+    scope.extend(self.inventory());
+    self.with_contents(scope)
+  }
+
+  /// Add the contents of any open containers and supporters among the
+  /// objects, and of any among those, and so on.
+  fn with_contents(&self, mut scope: Vec<ObjectId>) -> Vec<ObjectId> {
+    let mut index = 0;
+    while index < scope.len() {
+      if let Some(object) = self.object(&scope[index]) {
+        if object.reveals_contents() {
+          scope.extend(self.contents(&Location::In(scope[index].clone())));
+          scope.extend(self.contents(&Location::On(scope[index].clone())));
+        }
+      }
+      index += 1;
+    }
+    scope
+  }
+
+  /// Find an object in scope that the player's words could refer to.
+  pub fn find_object(&self, words: &[&str]) -> Option<ObjectId> {
+    self.find_objects(words).into_iter().next()
+  }
+
+  /// Whether the word is a noun or adjective of any object in the world,
+  /// whether or not it is in scope.
+  pub fn knows_word(&self, word: &str) -> bool {
+    self
+      .objects
+      .values()
+      .any(|object| object.has_noun(word) || object.has_adjective(word))
+  }
+
+  /// Find all of the objects in scope that the player's words could refer to.
+  pub fn find_objects(&self, words: &[&str]) -> Vec<ObjectId> {
+    self
+      .objects_in_scope()
+      .into_iter()
+      .filter(|id| self.object(id).is_some_and(|object| object.matches(words)))
+      .collect()
+  }
+
+  /// List the names of the objects with indefinite articles.
+  pub fn list_objects(&self, ids: &[ObjectId]) -> Vec<String> {
+    ids
+      .iter()
+      .filter_map(|id| self.object(id))
+      .map(|object| object.indefinite_name())
+      .collect()
+  }
+
+  /// Describe the room the player is currently in, including any objects.
+  ///
+  /// In the dark, this is the "dark" message, if there is one, or
+  /// [`DARKNESS`].
+  pub fn describe_current_room(&self) -> Option<String> {
+    let room = self.current_room()?;
+    if !self.is_lit() {
+      return Some(self.message("dark").unwrap_or(DARKNESS).to_string());
+    }
+    let mut lines = vec![room.describe(&self.clock)];
+    lines.extend(
+      self
+        .doors()
+        .iter()
+        .filter_map(|id| self.object(id))
+        .filter_map(Object::describe_openness),
+    );
+    for id in self.contents(&Location::Room(self.player_location.clone())) {
+      let object = &self.objects[&id];
+      lines.push(format!("There is {} here.", object.indefinite_name()));
+      if object.reveals_contents() {
+        lines.extend(self.describe_contents(&id));
+      }
+    }
+    Some(lines.join("\n"))
+  }
+
+  /// Describe the visible contents of an object, if it has any.
+  pub fn describe_contents(&self, id: &str) -> Option<String> {
+    let object = self.object(id)?;
+    let (preposition, location) = if object.supporter {
+      ("Sitting on", Location::On(id.to_string()))
+    } else {
+      ("Inside", Location::In(id.to_string()))
+    };
+    let contents = self.list_objects(&self.contents(&location));
+    if contents.is_empty() {
+      return None;
+    }
+    Some(format!(
+      "{} {} is: {}.",
+      preposition,
+      object.definite_name(),
+      contents.join(", ")
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils;
+  use std::io::Write;
+  use tempfile::NamedTempFile;
+
+  fn test_world() -> World {
+    World::new()
+      .with_room(
+        "west_of_house",
+        Room::new("West of House", "You are standing in an open field.").with_exit("north", "north_of_house"),
+      )
+      .with_room(
+        "north_of_house",
+        Room::new("North of House", "You are facing the north side of a white house."),
+      )
+      .with_object(
+        "mailbox",
+        Object::new("small mailbox", "It's a small mailbox.")
+          .at(Location::Room("west_of_house".to_string()))
+          .fixed()
+          .container(),
+      )
+      .with_object(
+        "leaflet",
+        Object::new("leaflet", "WELCOME TO SORK!").at(Location::In("mailbox".to_string())),
+      )
+      .with_object(
+        "lantern",
+        Object::new("brass lantern", "A battery-powered brass lantern.").at(Location::Player),
+      )
+      .with_object(
+        "sword",
+        Object::new("elvish sword", "An elvish sword.").at(Location::Room("north_of_house".to_string())),
+      )
+      .with_player_location("west_of_house")
+  }
+
+  #[test]
+  fn test_from_toml() {
+    test_utils::init();
+    let world = World::from_toml(
+      r#"
+      player_location = "west_of_house"
+
+      [clock]
+      minutes = 1200
+      minutes_per_turn = 5
+
+      [messages]
+      intro = "Welcome!"
+
+      [rooms.west_of_house]
+      name = "West of House"
+      description = "You are standing in an open field."
+      exits = { north = "north_of_house" }
+
+      [rooms.north_of_house]
+      name = "North of House"
+      description = "You are facing the north side of a white house."
+      exits = { west = { destination = "west_of_house" } }
+
+      [objects.mailbox]
+      name = "small mailbox"
+      description = "It's a small mailbox."
+      location = { room = "west_of_house" }
+      fixed = true
+      container = true
+
+      [objects.leaflet]
+      name = "leaflet"
+      description = "WELCOME TO SORK!"
+      location = { in = "mailbox" }
+
+      [objects.lantern]
+      name = "brass lantern"
+      location = "player"
+      "#,
+    )
+    .unwrap();
+    assert_eq!(world.player_location, "west_of_house");
+    assert_eq!(world.message("intro"), Some("Welcome!"));
+    assert_eq!(world.message("outro"), None);
+    assert_eq!(world.clock, Clock::new(20, 0).with_minutes_per_turn(5));
+    assert_eq!(
+      world.current_room().unwrap().exit("north").unwrap().destination,
+      "north_of_house"
+    );
+    assert_eq!(
+      world.room("north_of_house").unwrap().exit("west").unwrap().destination,
+      "west_of_house"
+    );
+    assert_eq!(
+      world.object("mailbox").unwrap().location,
+      Location::Room("west_of_house".to_string())
+    );
+    assert!(world.object("mailbox").unwrap().fixed);
+    assert_eq!(
+      world.object("leaflet").unwrap().location,
+      Location::In("mailbox".to_string())
+    );
+    assert_eq!(world.inventory(), vec!["lantern"]);
+  }
+
+  #[test]
+  fn test_from_toml_syntax_error() {
+    test_utils::init();
+    let result = World::from_toml("player_location = \"west_of_house\"\n[rooms.west_of_house\nname = \"West\"\n");
+    assert!(matches!(result, Err(WorldError::SyntaxError { line: 2, .. })));
+    let result = World::from_toml("[rooms.west_of_house]\nname = \"West\"\ncolour = \"red\"\n");
+    assert!(matches!(
+      result,
+      Err(WorldError::SyntaxError { line: 3, column: 1, .. })
+    ));
+  }
+
+  #[test]
+  fn test_from_toml_invalid_world() {
+    test_utils::init();
+    let result = World::from_toml("player_location = \"nowhere\"");
+    assert!(matches!(result, Err(WorldError::InvalidWorld(_))));
+    let result = World::from_toml("[rooms.west_of_house]\nexits = { north = \"nowhere\" }");
+    assert!(matches!(result, Err(WorldError::InvalidWorld(_))));
+    let result = World::from_toml("[objects.leaflet]\nlocation = { in = \"mailbox\" }");
+    assert!(matches!(result, Err(WorldError::InvalidWorld(_))));
+    let result = World::from_toml("[objects.x]\nlocation = { in = \"y\" }\n[objects.y]\nlocation = { on = \"x\" }");
+    assert!(matches!(result, Err(WorldError::InvalidWorld(_))));
+  }
+
+  #[test]
+  fn test_load() {
+    test_utils::init();
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(file, "[rooms.west_of_house]\nname = \"West of House\"").unwrap();
+    file.flush().unwrap();
+    let world = World::load(file.path()).unwrap();
+    assert_eq!(world.room("west_of_house").unwrap().name, "West of House");
+    assert!(matches!(
+      World::load("/nonexistent/world.toml"),
+      Err(WorldError::IoError(_))
+    ));
+  }
+
+  #[test]
+  fn test_load_sork() {
+    test_utils::init();
+    let world = World::load(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/sork/world.toml")).unwrap();
+    assert_eq!(world.current_room().unwrap().name, "West of House");
+    assert!(world
+      .object("leaflet")
+      .unwrap()
+      .description
+      .starts_with("\"WELCOME TO SORK!\n\nSORK is"));
+  }
+
+  #[test]
+  fn test_new() {
+    test_utils::init();
+    let world = World::new();
+    assert!(world.rooms.is_empty());
+    assert!(world.objects.is_empty());
+    assert!(world.current_room().is_none());
+    assert!(world.describe_current_room().is_none());
+  }
+
+  #[test]
+  fn test_room() {
+    test_utils::init();
+    let world = test_world();
+    assert_eq!(world.room("north_of_house").unwrap().name, "North of House");
+    assert!(world.room("behind_house").is_none());
+  }
+
+  #[test]
+  fn test_current_room() {
+    test_utils::init();
+    let mut world = test_world();
+    assert_eq!(world.current_room().unwrap().name, "West of House");
+    world.player_location = "north_of_house".to_string();
+    assert_eq!(world.current_room().unwrap().name, "North of House");
+  }
+
+  #[test]
+  fn test_contents() {
+    test_utils::init();
+    let world = test_world();
+    assert_eq!(world.contents(&Location::In("mailbox".to_string())), vec!["leaflet"]);
+    assert_eq!(world.inventory(), vec!["lantern"]);
+  }
+
+  #[test]
+  fn test_is_within() {
+    test_utils::init();
+    let world = test_world();
+    assert!(world.is_within("leaflet", "mailbox"));
+    assert!(!world.is_within("mailbox", "leaflet"));
+    assert!(!world.is_within("lantern", "mailbox"));
+  }
+
+  #[test]
+  fn test_objects_in_scope() {
+    test_utils::init();
+    let mut world = test_world();
+    assert_eq!(world.objects_in_scope(), vec!["mailbox", "lantern", "leaflet"]);
+    world.object_mut("mailbox").unwrap().closed = true;
+    assert_eq!(world.objects_in_scope(), vec!["mailbox", "lantern"]);
+  }
+
+  #[test]
+  fn test_darkness() {
+    test_utils::init();
+    let mut world = test_world();
+    world.rooms.get_mut("west_of_house").unwrap().dark = true;
+    assert!(!world.is_lit());
+    assert_eq!(world.objects_in_scope(), vec!["lantern"]);
+    assert_eq!(world.describe_current_room().unwrap(), DARKNESS);
+    world
+      .messages
+      .insert("dark".to_string(), "Beware of the grue.".to_string());
+    assert_eq!(world.describe_current_room().unwrap(), "Beware of the grue.");
+    let lantern = world.object_mut("lantern").unwrap();
+    lantern.light_source = true;
+    lantern.lit = true;
+    assert!(world.is_lit());
+    assert_eq!(world.objects_in_scope(), vec!["mailbox", "lantern", "leaflet"]);
+    // A light inside a closed container gives no light.
+    world.object_mut("lantern").unwrap().location = Location::In("mailbox".to_string());
+    world.object_mut("mailbox").unwrap().closed = true;
+    assert!(!world.is_lit());
+  }
+
+  #[test]
+  fn test_doors() {
+    test_utils::init();
+    let mut world = test_world().with_object("door", Object::new("wooden door", "").fixed().openable());
+    for (id, exit, destination) in [
+      ("west_of_house", "north", "north_of_house"),
+      ("north_of_house", "south", "west_of_house"),
+    ] {
+      let room = world.rooms.remove(id).unwrap();
+      world.add_room(id, room.with_exit_through(exit, destination, "door"));
+    }
+    assert!(world.validate().is_ok());
+    // The door's state is visible from both sides.
+    for room in ["west_of_house", "north_of_house"] {
+      world.player_location = room.to_string();
+      assert_eq!(world.doors(), vec!["door"]);
+      assert!(world.objects_in_scope().contains(&"door".to_string()));
+      assert!(world
+        .describe_current_room()
+        .unwrap()
+        .contains("The wooden door is open."));
+    }
+    world.object_mut("door").unwrap().fixed = false;
+    assert!(world.validate().is_err());
+    world.object_mut("door").unwrap().fixed = true;
+    world.object_mut("door").unwrap().key = Some("skeleton_key".to_string());
+    assert!(world.validate().is_err());
+  }
+
+  #[test]
+  fn test_route() {
+    test_utils::init();
+    let mut world = test_world()
+      .with_room(
+        "behind_house",
+        Room::new("Behind House", "").with_exit_through("north", "north_of_house", "window"),
+      )
+      .with_object("window", Object::new("window", "").fixed().openable());
+    world.add_room(
+      "north_of_house",
+      Room::new("North of House", "")
+        .with_exit("west", "west_of_house")
+        .with_exit("east", "behind_house"),
+    );
+    assert_eq!(world.route("west_of_house", "west_of_house"), Some(vec![]));
+    assert_eq!(
+      world.route("behind_house", "west_of_house"),
+      Some(vec!["north".to_string(), "west".to_string()])
+    );
+    assert_eq!(world.route("west_of_house", "nowhere"), None);
+    world.object_mut("window").unwrap().closed = true;
+    assert_eq!(world.route("behind_house", "north_of_house"), None);
+  }
+
+  #[test]
+  fn test_find_object() {
+    test_utils::init();
+    let world = test_world();
+    assert_eq!(world.find_object(&["lantern"]), Some("lantern".to_string()));
+    assert_eq!(world.find_object(&["small", "mailbox"]), Some("mailbox".to_string()));
+    assert_eq!(world.find_object(&["sword"]), None);
+    assert_eq!(world.find_objects(&["sword"]), Vec::<ObjectId>::new());
+    assert_eq!(world.find_objects(&["leaflet"]), vec!["leaflet"]);
+  }
+
+  #[test]
+  fn test_knows_word() {
+    test_utils::init();
+    let world = test_world();
+    assert!(world.knows_word("mailbox"));
+    assert!(world.knows_word("small"));
+    assert!(!world.knows_word("unicorn"));
+  }
+
+  #[test]
+  fn test_describe_current_room() {
+    test_utils::init();
+    let world = test_world();
+    assert_eq!(
+      world.describe_current_room().unwrap(),
+      "West of House\nYou are standing in an open field.\nThere is a small mailbox here.\nInside the small mailbox \
+       is: a leaflet."
+    );
+  }
+}